@@ -1,34 +1,125 @@
 use color_eyre::Result;
-use rand::Rng;
-use ratatui::{Terminal, backend::CrosstermBackend};
-use std::time::{Duration, Instant};
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use ratatui::{Terminal, backend::CrosstermBackend, layout::Rect};
+use std::io::stdout;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use crate::audio::AudioManager;
+use crate::bindings::Control;
 use crate::entities::{
-    Enemy, EnemyType, Formation, FormationType, GameState, Particle, Pickup, Player, Projectile,
-    ProjectileOwner, ProjectileType, WeaponType, create_explosion_particles,
+    DropTable, Enemy, EnemyType, FloatingText, FloatingTextKind, Formation, FormationType,
+    GameState, LaserBeam, MovementBounds, Particle, Pickup, PickupKind, Player, Projectile,
+    ProjectileInteraction, ProjectileOwner, ProjectileType, SHIELD_DURATION_FRAMES, SHIELD_HITS,
+    SHIPS, SHOOTABLE_ENEMY_PROJECTILES, SPEED_BOOST_FRAMES, ScreenStack, ShipPassive, WeaponType,
+    create_explosion_particles, create_shockwave_particles,
 };
-use crate::input::{InputAction, InputManager};
+use crate::high_scores::{HighScoreEntry, HighScoreTable, InitialsEntry};
+use crate::input::{InputAction, InputManager, steer_toward};
+use crate::menu::{ControlsItem, MainMenuItem, MenuCursor, PauseMenuItem, SettingsItem};
 use crate::renderer::{GameRenderer, RenderView};
+use crate::run_stats::{DamageSource, RunRecord, RunStats};
+use crate::settings::Settings;
+
+/// Health restored by a repair kit pickup
+const REPAIR_AMOUNT: u8 = 25;
+
+/// Score awarded by a medal pickup
+const MEDAL_POINTS: u32 = 100;
+
+/// Damage a smart bomb deals to every enemy on screen
+const SMART_BOMB_DAMAGE: u8 = 50;
+
+/// Chance per frame that an enemy able to fire does so, before difficulty scaling
+const ENEMY_FIRE_CHANCE: f64 = 0.1;
+
+/// Frames the play area shakes after the ship is hit
+const SHAKE_FRAMES: u8 = 8;
+
+/// Chance per frame that a formation enemy breaks away to dive at the player
+const DIVE_CHANCE: f64 = 0.004;
+
+/// Damage a ship takes from ramming an enemy
+const COLLISION_DAMAGE: u8 = 20;
 
 /// The main application which holds the state and logic of the application.
 pub struct App {
     running: bool,
-    game_state: GameState,
-    player: Player,
+    /// Open screens, the top one is active
+    screens: ScreenStack,
+    main_menu: MenuCursor,
+    settings_menu: MenuCursor,
+    pause_menu: MenuCursor,
+    /// Pause menu choice waiting on the confirmation prompt
+    pending_confirmation: Option<PauseMenuItem>,
+    /// Whether "Yes" is highlighted on the confirmation prompt
+    confirm_yes: bool,
+    controls_menu: MenuCursor,
+    /// Control waiting for a key press on the controls screen
+    rebinding: Option<Control>,
+    /// Result of the last change on the controls screen, e.g. a key already in use
+    binding_notice: Option<String>,
+    /// Ships in play, indexed by player id; a second one flies in co-op
+    players: Vec<Player>,
     enemies: Vec<Enemy>,
+    /// Id handed to the next spawned enemy
+    next_enemy_id: u32,
     formations: Vec<Formation>,
     /// Projectiles (from player and enemies)
     projectiles: Vec<Projectile>,
     particles: Vec<Particle>,
+    floating_texts: Vec<FloatingText>,
     pickups: Vec<Pickup>,
-    score: u32,
+    /// Formations that lost an enemy without it being shot down
+    broken_formations: Vec<usize>,
+    /// Decides which destroyed enemies drop pickups
+    drop_table: DropTable,
+    /// Laser beams fired during the last frame
+    laser_beams: Vec<LaserBeam>,
+    /// Player options in effect, including command-line overrides, kept across restarts
+    settings: Settings,
+    /// Options as stored in the config file plus in-game changes, without the overrides
+    saved_settings: Settings,
+    /// Where settings are saved when changed, None to keep them for this session only
+    settings_path: Option<PathBuf>,
+    /// Index into SHIPS highlighted on the ship selection screen, kept across restarts
+    selected_ship: usize,
+    /// Whether runs start with two players sharing the keyboard, kept across restarts
+    co_op: bool,
+    /// Ships picked so far on the selection screen, by player, kept for restarts
+    ship_picks: Vec<usize>,
+    /// Enemy projectile types that player shots can destroy
+    shootable_enemy_projectiles: Vec<ProjectileType>,
+    /// Seed for this run's random number generator, recorded with high scores
+    seed: u64,
+    rng: StdRng,
+    high_scores: HighScoreTable,
+    /// Where the high-score table is saved, None if there's no data directory
+    high_scores_path: Option<PathBuf>,
+    /// Initials being entered for a qualifying score
+    initials: InitialsEntry,
+    /// Table position of this run's score once entered
+    last_rank: Option<usize>,
+    /// Frames left of screen shake from the last hit
+    shake_frames: u8,
+    /// Shots, hits, kills and other counters for this run
+    stats: RunStats,
     /// screen dimensions
     screen_width: u16,
     screen_height: u16,
     edge_width: u16,
+    /// Whether the terminal is currently reporting mouse events
+    mouse_captured: bool,
+    /// Whether the terminal reports key releases; held keys time out when it doesn't
+    key_releases: bool,
     /// Frames info
     frame_count: u64,
+    /// Frames drawn since launch, drives menu animations while the game isn't running
+    ui_frame_count: u64,
     spawn_delay_frames: u64,
     last_frame_time: Instant,
     fps: u32,
@@ -42,6 +133,27 @@ pub struct App {
 }
 
 impl App {
+    /// Construct a new instance of [`App`] with the given settings. In-game changes are
+    /// applied to `saved_settings` (the config file's contents, without command-line
+    /// overrides) and saved to `settings_path` if one is given. `key_releases` says whether
+    /// the terminal reports key releases (keyboard enhancement).
+    pub fn new_with_settings(
+        settings: Settings,
+        saved_settings: Settings,
+        settings_path: Option<PathBuf>,
+        key_releases: bool,
+    ) -> Self {
+        let mut app = Self::new();
+        app.key_releases = key_releases;
+        // main.rs turns on mouse capture at startup when mouse control is saved as on
+        app.mouse_captured = settings.mouse_control;
+        app.settings = settings;
+        app.saved_settings = saved_settings;
+        app.settings_path = settings_path;
+        app.apply_settings();
+        app
+    }
+
     /// Construct a new instance of [`App`].
     pub fn new() -> Self {
         // Start with reasonable defaults, will be updated on first render
@@ -54,20 +166,56 @@ impl App {
         let player_y = screen_height - (screen_height / 5); // Center horizontally on screen
 
         let now = Instant::now();
+        let seed = rand::rng().random();
+        let high_scores_path = HighScoreTable::default_path();
+
         let mut app = Self {
             running: true,
-            game_state: GameState::Playing,
-            player: Player::new(player_x, player_y),
+            screens: ScreenStack::new(GameState::Title),
+            main_menu: MenuCursor::new(),
+            settings_menu: MenuCursor::new(),
+            pause_menu: MenuCursor::new(),
+            pending_confirmation: None,
+            confirm_yes: false,
+            controls_menu: MenuCursor::new(),
+            rebinding: None,
+            binding_notice: None,
+            players: vec![Player::new(player_x, player_y)],
             enemies: Vec::new(),
+            next_enemy_id: 0,
             formations: Vec::new(),
             projectiles: Vec::new(),
             particles: Vec::new(),
+            floating_texts: Vec::new(),
             pickups: Vec::new(),
-            score: 0,
+            broken_formations: Vec::new(),
+            drop_table: DropTable::new(),
+            laser_beams: Vec::new(),
+            settings: Settings::default(),
+            saved_settings: Settings::default(),
+            settings_path: None,
+            selected_ship: 0,
+            co_op: false,
+            ship_picks: Vec::new(),
+            shootable_enemy_projectiles: SHOOTABLE_ENEMY_PROJECTILES.to_vec(),
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            high_scores: high_scores_path
+                .as_deref()
+                .map(HighScoreTable::load)
+                .unwrap_or_default(),
+            high_scores_path,
+            initials: InitialsEntry::default(),
+            last_rank: None,
+            shake_frames: 0,
+            stats: RunStats::new(),
             frame_count: 0,
+            ui_frame_count: 0,
             screen_width,
             screen_height,
             edge_width,
+            mouse_captured: false,
+            key_releases: true,
             spawn_delay_frames: 0,
             last_frame_time: now,
             fps: 0,
@@ -101,20 +249,42 @@ impl App {
             self.screen_height = area.height;
 
             // Render the frame
+            let score = self.get_score();
             terminal.draw(|frame| {
                 // Use final time if game is over, otherwise calculate current elapsed time
                 let elapsed_time_secs = self
                     .final_time_secs
                     .unwrap_or_else(|| self.game_start_time.elapsed().as_secs());
                 let view = RenderView {
-                    game_state: self.game_state,
-                    player: &self.player,
+                    screens: self.screens.get_visible(),
+                    main_menu: self.main_menu,
+                    settings_menu: self.settings_menu,
+                    pause_menu: self.pause_menu,
+                    pending_confirmation: self.pending_confirmation,
+                    confirm_yes: self.confirm_yes,
+                    controls_menu: self.controls_menu,
+                    rebinding: self.rebinding,
+                    binding_notice: self.binding_notice.as_deref(),
+                    settings: &self.settings,
+                    players: &self.players,
                     enemies: &self.enemies,
                     projectiles: &self.projectiles,
                     particles: &self.particles,
+                    floating_texts: &self.floating_texts,
                     pickups: &self.pickups,
-                    score: self.score,
+                    laser_beams: &self.laser_beams,
+                    movement_model: self.settings.movement_model,
+                    selected_ship: self.selected_ship,
+                    co_op: self.co_op,
+                    choosing_player: self.ship_picks.len(),
+                    score,
+                    shake_frames: self.shake_frames,
+                    stats: &self.stats,
+                    high_scores: &self.high_scores,
+                    initials: self.initials,
+                    last_rank: self.last_rank,
                     frame_count: self.frame_count,
+                    ui_frame_count: self.ui_frame_count,
                     area: frame.area(),
                     edge_width: self.edge_width,
                     fps: self.fps,
@@ -123,15 +293,18 @@ impl App {
                 self.renderer.render(frame, &view);
             })?;
 
+            self.ui_frame_count += 1;
+
             // Poll input events and get actions
-            self.input_manager.poll_events(&self.game_state)?;
-            let actions = self.input_manager.get_actions(&self.game_state);
+            let screen = self.screens.current();
+            self.input_manager.poll_events(&screen)?;
+            let actions = self.input_manager.get_actions(&screen);
 
             // Process all actions
             self.process_actions(&actions);
 
             // Update game state
-            if self.game_state == GameState::Playing {
+            if self.screens.current() == GameState::Playing {
                 self.update_game();
             }
 
@@ -143,61 +316,523 @@ impl App {
 
     /// Process input actions and update game state accordingly
     fn process_actions(&mut self, actions: &[InputAction]) {
+        // Beams only last for the frame they were fired in
+        self.laser_beams.clear();
+
+        // Focus applies to this frame's movement and firing
+        self.players[0].focused = actions.contains(&InputAction::Focus);
+        if let Some(player_two) = self.players.get_mut(1) {
+            player_two.focused = actions.contains(&InputAction::P2Focus);
+        }
+
+        // Held direction for this frame, used by steering and dashing
+        let held_direction = |left, right, up, down| {
+            (
+                actions.contains(&right) as i16 - actions.contains(&left) as i16,
+                actions.contains(&down) as i16 - actions.contains(&up) as i16,
+            )
+        };
+        self.players[0].steer_input = held_direction(
+            InputAction::MoveLeft,
+            InputAction::MoveRight,
+            InputAction::MoveUp,
+            InputAction::MoveDown,
+        );
+        if let Some(player_two) = self.players.get_mut(1) {
+            player_two.steer_input = held_direction(
+                InputAction::P2MoveLeft,
+                InputAction::P2MoveRight,
+                InputAction::P2MoveUp,
+                InputAction::P2MoveDown,
+            );
+        }
+
+        // With no direction keys held, mouse control steers player one toward the cursor
+        if self.players[0].steer_input == (0, 0)
+            && self.settings.mouse_control
+            && let Some(cursor) = self.input_manager.get_mouse_position()
+        {
+            let player = &mut self.players[0];
+            let ship = Rect::new(player.x, player.y, player.get_width(), player.get_height());
+            player.steer_input = steer_toward(cursor, ship, self.edge_width);
+        }
+
         for action in actions {
             match action {
                 InputAction::Quit => {
                     self.running = false;
                 }
                 InputAction::Pause => {
-                    self.game_state = GameState::Paused;
+                    self.pause_menu = MenuCursor::new();
+                    self.screens.push(GameState::Paused);
+                }
+                InputAction::Back if self.rebinding.is_some() => {
+                    self.rebinding = None;
                 }
-                InputAction::Resume => {
-                    self.game_state = GameState::Playing;
+                InputAction::Resume | InputAction::Back => {
+                    self.screens.pop();
                 }
                 InputAction::Restart => {
-                    *self = Self::new();
+                    self.restart(&[GameState::Title, GameState::MainMenu, GameState::ShipSelect]);
+                    self.ship_picks.clear();
+                }
+                InputAction::MenuUp => match self.screens.current() {
+                    GameState::EnterInitials => self.initials.next_letter(),
+                    GameState::MainMenu => self.main_menu.previous(MainMenuItem::ALL.len()),
+                    GameState::Settings => self.settings_menu.previous(SettingsItem::ALL.len()),
+                    GameState::Paused => self.pause_menu.previous(PauseMenuItem::ALL.len()),
+                    GameState::Controls => {
+                        self.controls_menu.previous(ControlsItem::get_all().len())
+                    }
+                    _ => {}
+                },
+                InputAction::MenuDown => match self.screens.current() {
+                    GameState::EnterInitials => self.initials.previous_letter(),
+                    GameState::MainMenu => self.main_menu.next(MainMenuItem::ALL.len()),
+                    GameState::Settings => self.settings_menu.next(SettingsItem::ALL.len()),
+                    GameState::Paused => self.pause_menu.next(PauseMenuItem::ALL.len()),
+                    GameState::Controls => self.controls_menu.next(ControlsItem::get_all().len()),
+                    _ => {}
+                },
+                InputAction::MenuLeft => match self.screens.current() {
+                    GameState::EnterInitials => self.initials.move_left(),
+                    GameState::ShipSelect => {
+                        self.selected_ship = (self.selected_ship + SHIPS.len() - 1) % SHIPS.len();
+                    }
+                    GameState::MainMenu | GameState::Settings => self.adjust_menu_item(false),
+                    GameState::Confirmation => self.confirm_yes = !self.confirm_yes,
+                    GameState::Controls => self.unbind_selected(),
+                    _ => {}
+                },
+                InputAction::MenuRight => match self.screens.current() {
+                    GameState::EnterInitials => self.initials.move_right(),
+                    GameState::ShipSelect => {
+                        self.selected_ship = (self.selected_ship + 1) % SHIPS.len();
+                    }
+                    GameState::MainMenu | GameState::Settings => self.adjust_menu_item(true),
+                    GameState::Confirmation => self.confirm_yes = !self.confirm_yes,
+                    _ => {}
+                },
+                InputAction::TypeChar(c) => {
+                    self.initials.type_char(*c);
+                }
+                InputAction::BindKey(binding) => {
+                    if let Some(control) = self.rebinding.take() {
+                        self.binding_notice = match self.settings.bindings.bind(control, *binding) {
+                            Ok(()) => {
+                                self.apply_settings();
+                                self.save_settings(SettingsItem::Controls);
+                                None
+                            }
+                            Err(other) => Some(format!(
+                                "{} is already bound to {}",
+                                binding,
+                                other.get_label()
+                            )),
+                        };
+                    }
                 }
-                InputAction::MoveLeft => {
-                    // Player coordinates are relative to game area, so min is 0
-                    let min_x = 0;
-                    self.player.move_left(min_x);
+                InputAction::Confirm => self.confirm(),
+                InputAction::MoveLeft
+                | InputAction::MoveRight
+                | InputAction::MoveUp
+                | InputAction::MoveDown
+                | InputAction::P2MoveLeft
+                | InputAction::P2MoveRight
+                | InputAction::P2MoveUp
+                | InputAction::P2MoveDown => {
+                    // Movement is applied once per frame in update_game
                 }
-                InputAction::MoveRight => {
-                    // Max x is based on playable game area width
-                    // Game area width = screen_width - (edge_width * 2) - 2 (for borders)
-                    // The player occupies positions [x, x+width), so max valid x is width - player_width
-                    let game_area_width = self.screen_width.saturating_sub(self.edge_width * 2 + 2);
-                    // Use saturating_sub to prevent underflow, then subtract 1 more for safety
-                    let max_x = game_area_width.saturating_sub(self.player.get_width() + 1);
-                    self.player.move_right(max_x);
+                InputAction::Dash => self.dash(0),
+                InputAction::P2Dash => self.dash(1),
+                InputAction::ToggleMovement => {
+                    self.settings.adjust(SettingsItem::Handling, true);
+                    self.save_settings(SettingsItem::Handling);
                 }
-                InputAction::MoveUp => {
-                    let min_y = 2; // Leave space for HUD
-                    self.player.move_up(min_y);
+                InputAction::ToggleFloatingText => {
+                    self.settings.adjust(SettingsItem::FloatingText, true);
+                    self.save_settings(SettingsItem::FloatingText);
                 }
-                InputAction::MoveDown => {
-                    let max_y = self
-                        .screen_height
-                        .saturating_sub(self.player.get_height() + 1);
-                    self.player.move_down(max_y);
+                InputAction::Focus | InputAction::P2Focus => {
+                    // Already applied above
                 }
-                InputAction::Fire => {
-                    let new_projectiles = self.player.try_fire();
-                    if !new_projectiles.is_empty() {
-                        self.audio_manager.play_fire_sound();
+                InputAction::Bomb => self.detonate_smart_bomb(0),
+                InputAction::P2Bomb => self.detonate_smart_bomb(1),
+                InputAction::Fire => self.fire(0),
+                InputAction::P2Fire => self.fire(1),
+            }
+        }
+    }
+
+    /// Dashes a player's ship in its held direction
+    fn dash(&mut self, id: usize) {
+        if let Some(player) = self.players.get_mut(id).filter(|player| player.is_alive()) {
+            let (dx, dy) = player.steer_input;
+            player.try_dash(dx, dy);
+        }
+    }
+
+    /// Fires a player's weapon, as long as their ship is still flying
+    fn fire(&mut self, id: usize) {
+        let Some(player) = self.players.get_mut(id).filter(|player| player.is_alive()) else {
+            return;
+        };
+        if player.current_weapon == WeaponType::Laser {
+            self.fire_laser(id);
+            return;
+        }
+
+        let new_projectiles = player.try_fire();
+        self.stats
+            .record_shots(player.current_weapon, new_projectiles.len() as u32);
+        if !new_projectiles.is_empty() {
+            self.audio_manager.play_fire_sound();
+        }
+        self.projectiles.extend(new_projectiles);
+    }
+
+    /// Activates the highlighted entry or moves on from the current screen
+    fn confirm(&mut self) {
+        match self.screens.current() {
+            GameState::Title => self.screens.push(GameState::MainMenu),
+            GameState::MainMenu => match self.main_menu.get_selected(&MainMenuItem::ALL) {
+                MainMenuItem::Start => {
+                    self.ship_picks.clear();
+                    self.screens.push(GameState::ShipSelect);
+                }
+                MainMenuItem::Mode | MainMenuItem::Players => self.adjust_menu_item(true),
+                MainMenuItem::Settings => self.screens.push(GameState::Settings),
+                MainMenuItem::HighScores => self.screens.push(GameState::HighScores),
+                MainMenuItem::Quit => self.running = false,
+            },
+            GameState::Settings => match self.settings_menu.get_selected(&SettingsItem::ALL) {
+                SettingsItem::Controls => self.open_controls(),
+                SettingsItem::Back => {
+                    self.screens.pop();
+                }
+                _ => self.adjust_menu_item(true),
+            },
+            GameState::HighScores => {
+                self.screens.pop();
+            }
+            GameState::ShipSelect => self.pick_ship(),
+            GameState::Paused => {
+                let item = self.pause_menu.get_selected(&PauseMenuItem::ALL);
+                if item.is_destructive() {
+                    // Default to "No" so a double press of Enter can't lose the run
+                    self.pending_confirmation = Some(item);
+                    self.confirm_yes = false;
+                    self.screens.push(GameState::Confirmation);
+                } else {
+                    self.choose_pause_item(item);
+                }
+            }
+            GameState::Controls => {
+                match self.controls_menu.get_selected(&ControlsItem::get_all()) {
+                    ControlsItem::Bind(control) => {
+                        self.rebinding = Some(control);
+                        self.binding_notice = None;
+                        self.input_manager.start_capture();
+                    }
+                    ControlsItem::ResetDefaults => {
+                        self.settings.bindings = Default::default();
+                        self.binding_notice = Some("Controls reset to defaults".to_string());
+                        self.apply_settings();
+                        self.save_settings(SettingsItem::Controls);
                     }
-                    self.projectiles.extend(new_projectiles);
+                    ControlsItem::Back => {
+                        self.screens.pop();
+                    }
+                }
+            }
+            GameState::Confirmation => {
+                self.screens.pop();
+                if let Some(item) = self.pending_confirmation.take()
+                    && self.confirm_yes
+                {
+                    self.choose_pause_item(item);
+                }
+            }
+            GameState::EnterInitials => self.record_high_score(),
+            GameState::GameOver => {
+                self.restart(&[GameState::Title, GameState::MainMenu]);
+            }
+            GameState::Playing => {}
+        }
+    }
+
+    /// Carries out a pause menu choice, after confirmation for destructive ones
+    fn choose_pause_item(&mut self, item: PauseMenuItem) {
+        match item {
+            PauseMenuItem::Resume => {
+                self.screens.pop();
+            }
+            PauseMenuItem::Restart => {
+                self.restart(&[GameState::Title, GameState::MainMenu]);
+                self.launch();
+            }
+            PauseMenuItem::Settings => self.screens.push(GameState::Settings),
+            PauseMenuItem::Controls => self.open_controls(),
+            PauseMenuItem::QuitToMenu => {
+                self.restart(&[GameState::Title, GameState::MainMenu]);
+            }
+            PauseMenuItem::Quit => self.running = false,
+        }
+    }
+
+    /// Takes the highlighted ship for the next player to choose, launching once every
+    /// player has one
+    fn pick_ship(&mut self) {
+        self.ship_picks.push(self.selected_ship);
+        let player_count = if self.co_op { 2 } else { 1 };
+        if self.ship_picks.len() >= player_count {
+            self.launch();
+        }
+    }
+
+    /// Launches the picked ships; the menus are left behind and the run's clock starts now
+    fn launch(&mut self) {
+        let (x, y) = (self.players[0].x, self.players[0].y);
+        let game_area_width = self.screen_width.saturating_sub(self.edge_width * 2 + 2);
+        let player_count = self.ship_picks.len();
+        self.players = self
+            .ship_picks
+            .iter()
+            .enumerate()
+            .map(|(id, &ship_index)| {
+                // Co-op ships start a third of the way in from either side
+                let x = if player_count > 1 {
+                    (game_area_width * (id as u16 + 1) / 3)
+                        .saturating_sub(SHIPS[ship_index].width / 2)
+                } else {
+                    x
+                };
+                let mut player = Player::new_with_ship(x, y, ship_index);
+                player.id = id;
+                player
+            })
+            .collect();
+        self.input_manager.set_co_op(player_count > 1);
+        self.screens.reset(&[GameState::Playing]);
+        self.game_start_time = Instant::now();
+    }
+
+    /// Opens the key bindings screen at the top of the list
+    fn open_controls(&mut self) {
+        self.controls_menu = MenuCursor::new();
+        self.rebinding = None;
+        self.binding_notice = None;
+        self.screens.push(GameState::Controls);
+    }
+
+    /// Removes the most recently added key from the highlighted control
+    fn unbind_selected(&mut self) {
+        if let ControlsItem::Bind(control) =
+            self.controls_menu.get_selected(&ControlsItem::get_all())
+            && self.settings.bindings.unbind_last(control).is_some()
+        {
+            self.binding_notice = None;
+            self.apply_settings();
+            self.save_settings(SettingsItem::Controls);
+        }
+    }
+
+    /// Changes the value of the highlighted option on the main menu or settings screen
+    fn adjust_menu_item(&mut self, forward: bool) {
+        let item = match self.screens.current() {
+            GameState::MainMenu => match self.main_menu.get_selected(&MainMenuItem::ALL) {
+                MainMenuItem::Mode => SettingsItem::Handling,
+                MainMenuItem::Players => {
+                    // One or two players, so either direction switches
+                    self.co_op = !self.co_op;
+                    return;
                 }
+                _ => return,
+            },
+            GameState::Settings => self.settings_menu.get_selected(&SettingsItem::ALL),
+            _ => return,
+        };
+
+        self.settings.adjust(item, forward);
+        self.apply_settings();
+        self.save_settings(item);
+    }
+
+    /// Pushes settings that live in other components (sprites, volume, keys) out to them
+    fn apply_settings(&mut self) {
+        self.renderer.set_render_mode(self.settings.render_mode);
+        self.input_manager
+            .set_bindings(self.settings.bindings.clone());
+        self.input_manager
+            .set_mouse_enabled(self.settings.mouse_control);
+        self.input_manager
+            .set_key_repeat_fallback((!self.key_releases).then_some(self.settings.key_repeat));
+        if self.settings.mouse_control != self.mouse_captured {
+            // Losing mouse reporting only costs mouse control, so errors are ignored
+            let _ = if self.settings.mouse_control {
+                execute!(stdout(), EnableMouseCapture)
+            } else {
+                execute!(stdout(), DisableMouseCapture)
+            };
+            self.mouse_captured = self.settings.mouse_control;
+        }
+        self.audio_manager
+            .set_volume(self.settings.get_effective_volume());
+    }
+
+    /// Writes an option the player just changed to the config file, leaving out
+    /// command-line overrides of the others
+    fn save_settings(&mut self, item: SettingsItem) {
+        self.saved_settings.copy_item(&self.settings, item);
+        // A read-only config directory shouldn't stop play; changes still apply this session
+        if let Some(path) = &self.settings_path {
+            let _ = self.saved_settings.save(path);
+        }
+    }
+
+    /// Starts a fresh run on the given screens, keeping the player's choices
+    fn restart(&mut self, screens: &[GameState]) {
+        let selected_ship = self.selected_ship;
+        let main_menu = self.main_menu;
+        let co_op = self.co_op;
+        let ship_picks = std::mem::take(&mut self.ship_picks);
+        *self = Self::new_with_settings(
+            self.settings.clone(),
+            self.saved_settings.clone(),
+            self.settings_path.take(),
+            self.key_releases,
+        );
+        self.selected_ship = selected_ship;
+        self.main_menu = main_menu;
+        self.co_op = co_op;
+        self.ship_picks = ship_picks;
+        self.screens.reset(screens);
+    }
+
+    /// Score for the whole run, both players' added together in co-op
+    fn get_score(&self) -> u32 {
+        self.players.iter().map(|player| player.score).sum()
+    }
+
+    /// Area a player's ship may move within
+    fn player_bounds(&self, player: &Player) -> MovementBounds {
+        MovementBounds::for_game_area(
+            self.screen_width,
+            self.screen_height,
+            self.edge_width,
+            player.get_width(),
+            player.get_height(),
+        )
+    }
+
+    /// Spends one of a player's bomb charges to clear enemy fire and blast every enemy on
+    /// screen
+    fn detonate_smart_bomb(&mut self, id: usize) {
+        let Some(player) = self.players.get_mut(id).filter(|player| player.is_alive()) else {
+            return;
+        };
+        if !player.use_bomb() {
+            return;
+        }
+        self.stats.record_bomb();
+
+        // Shockwave from the ship
+        let center_x = player.x + player.get_width() / 2;
+        let center_y = player.y + player.get_height() / 2;
+        self.particles.extend(
+            self.settings
+                .particles
+                .thin(create_shockwave_particles(center_x, center_y)),
+        );
+
+        // Clear all enemy projectiles
+        self.projectiles
+            .retain(|p| p.owner != ProjectileOwner::Enemy);
+
+        // Heavy damage to every enemy on screen
+        let mut enemies_to_remove = Vec::new();
+        for (e_idx, enemy) in self.enemies.iter_mut().enumerate() {
+            if enemy.y >= self.screen_height {
+                continue;
+            }
+
+            enemy.take_damage_from(SMART_BOMB_DAMAGE, id);
+            self.floating_texts
+                .push(Self::damage_number(enemy, SMART_BOMB_DAMAGE));
+
+            if !enemy.is_alive() {
+                // Create particles at enemy death location
+                let enemy_center_x = enemy.x + enemy.get_width() / 2;
+                let enemy_center_y = enemy.y + enemy.get_height() / 2;
+                let death_particles = create_explosion_particles(enemy_center_x, enemy_center_y);
+                self.particles
+                    .extend(self.settings.particles.thin(death_particles));
+                enemies_to_remove.push(e_idx);
             }
         }
+
+        self.remove_enemies(enemies_to_remove);
+        self.audio_manager.play_fire_sound();
+    }
+
+    /// Casts a player's laser beam for this frame and damages the first enemy in its path
+    fn fire_laser(&mut self, id: usize) {
+        let player = &mut self.players[id];
+        if !player.fire_laser() {
+            return;
+        }
+        // Holding the trigger is one shot, however many frames the beam lasts
+        if player.is_laser_burst_start() {
+            self.stats.record_shots(WeaponType::Laser, 1);
+        }
+
+        let beam_x = player.x + player.get_width() / 2;
+        let beam_y = player.y.saturating_sub(1);
+        // Damage per frame scales with weapon level
+        let damage = player.weapon_level;
+        let beam = LaserBeam::cast(beam_x, beam_y, &self.enemies);
+
+        if let Some(e_idx) = beam.hit_enemy {
+            // A burst is one hit however many enemies it strikes
+            if player.land_laser_hit() {
+                self.stats.record_hit();
+            }
+            let enemy = &mut self.enemies[e_idx];
+            enemy.take_damage_from(damage, id);
+
+            if !enemy.is_alive() {
+                // Create particles at enemy death location
+                let enemy_center_x = enemy.x + enemy.get_width() / 2;
+                let enemy_center_y = enemy.y + enemy.get_height() / 2;
+                let death_particles = create_explosion_particles(enemy_center_x, enemy_center_y);
+                self.particles
+                    .extend(self.settings.particles.thin(death_particles));
+                self.remove_enemies(vec![e_idx]);
+            }
+        }
+
+        self.laser_beams.push(beam);
     }
 
     /// Update game logic
     fn update_game(&mut self) {
         self.frame_count += 1;
 
-        // Update player cooldown
-        self.player.update_cooldown();
+        self.shake_frames = self.shake_frames.saturating_sub(1);
+
+        for id in 0..self.players.len() {
+            let bounds = self.player_bounds(&self.players[id]);
+            let player = &mut self.players[id];
+            player.update_cooldown();
+            player.score_chain.update();
+
+            // Move each ship still flying, then advance any dash in progress
+            if player.is_alive() {
+                let (dx, dy) = player.steer_input;
+                player.steer(dx, dy, self.settings.movement_model, &bounds);
+                player.update_dash(&bounds);
+            }
+        }
 
         // Check if all enemies are dead and spawn new formation after delay
         if self.enemies.is_empty() {
@@ -216,13 +851,35 @@ impl App {
 
         // Update projectiles
         for projectile in &mut self.projectiles {
-            projectile.update();
+            projectile.update_with_targets(&self.enemies);
+            // Sword slashes follow the ship that swung them as it moves
+            if projectile.projectile_type == ProjectileType::Slash
+                && let Some(player) = projectile
+                    .owner
+                    .get_player_id()
+                    .and_then(|id| self.players.get(id))
+            {
+                let (anchor_x, anchor_y) = player.get_slash_anchor();
+                projectile.follow(anchor_x, anchor_y);
+            }
         }
 
         // Remove out-of-bounds projectiles (coordinates are relative to game area)
         let game_area_width = self.screen_width.saturating_sub(self.edge_width * 2 + 2);
-        self.projectiles
-            .retain(|p| !p.is_out_of_bounds(0, game_area_width, self.screen_height));
+        // Expired bombs are kept until collision checks have resolved their explosion
+        self.projectiles.retain(|p| {
+            p.is_exploding() || !p.is_out_of_bounds(0, game_area_width, self.screen_height)
+        });
+
+        // Update floating texts, dropping them all if the player turned them off
+        if self.settings.floating_text {
+            for text in &mut self.floating_texts {
+                text.update();
+            }
+            self.floating_texts.retain(|t| !t.is_dead());
+        } else {
+            self.floating_texts.clear();
+        }
 
         // Update particles
         for particle in &mut self.particles {
@@ -234,6 +891,20 @@ impl App {
             !p.is_dead() && !p.is_out_of_bounds(0, game_area_width, self.screen_height)
         });
 
+        // Every so often an enemy breaks formation and dives
+        if self
+            .rng
+            .random_bool(DIVE_CHANCE * self.settings.difficulty.get_aggression())
+        {
+            let candidates: Vec<usize> = (0..self.enemies.len())
+                .filter(|&i| self.enemies[i].formation_id.is_some() && !self.enemies[i].diving)
+                .collect();
+            if !candidates.is_empty() {
+                let pick = candidates[self.rng.random_range(0..candidates.len())];
+                self.enemies[pick].start_dive();
+            }
+        }
+
         // Update formations
         let game_area_width = self.screen_width.saturating_sub(self.edge_width * 2 + 2);
         for formation in &mut self.formations {
@@ -243,14 +914,21 @@ impl App {
         // Update enemy positions based on formations
         for enemy in self.enemies.iter_mut() {
             if let Some(formation_id) = enemy.formation_id
-                && formation_id < self.formations.len() {
-                    let formation = &self.formations[formation_id];
-                    enemy.update_formation_position(formation.center_x, formation.center_y);
-                }
+                && formation_id < self.formations.len()
+                && !enemy.diving
+            {
+                let formation = &self.formations[formation_id];
+                enemy.update_formation_position(formation.center_x, formation.center_y);
+            }
 
             enemy.update();
 
-            if enemy.can_fire() && rand::rng().random_bool(0.1) {  // Reduced from 0.3 to 0.1 (10% chance)
+            // 10% chance at normal difficulty
+            if enemy.can_fire()
+                && self
+                    .rng
+                    .random_bool(ENEMY_FIRE_CHANCE * self.settings.difficulty.get_aggression())
+            {
                 let enemy_width = enemy.get_width();
                 let enemy_height = enemy.get_height();
                 // Fire from the center bottom of the enemy sprite
@@ -263,7 +941,14 @@ impl App {
         }
 
         // Remove enemies that went off screen
-        self.enemies.retain(|e| e.y < self.screen_height);
+        let off_screen = self
+            .enemies
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.y >= self.screen_height)
+            .map(|(idx, _)| idx)
+            .collect();
+        self.remove_enemies(off_screen);
 
         // Clean up formations that have no enemies left or went off screen
         self.formations.retain(|f| {
@@ -273,11 +958,6 @@ impl App {
                     .any(|&idx| idx < self.enemies.len() && self.enemies[idx].is_alive())
         });
 
-        // Spawn pickups more frequently (50% chance every 180 frames ~ every 3 seconds)
-        if self.frame_count.is_multiple_of(180) && rand::rng().random_bool(0.5) {
-            self.spawn_pickup();
-        }
-
         // Update pickups
         for pickup in &mut self.pickups {
             pickup.update();
@@ -288,20 +968,77 @@ impl App {
             .retain(|p| !p.is_out_of_bounds(self.screen_height));
 
         // Check collisions
+        self.check_projectile_collisions();
+        self.check_slash_hits();
         self.check_collisions();
 
-        // Check if player is dead
-        if !self.player.is_alive() {
-            // Capture final time when transitioning to game over
-            if self.game_state != GameState::GameOver {
-                self.final_time_secs = Some(self.game_start_time.elapsed().as_secs());
-            }
-            self.game_state = GameState::GameOver;
+        // The run ends once every ship is down
+        if !self.players.iter().any(Player::is_alive) {
+            // Capture final time and stats when transitioning to game over
+            self.final_time_secs = Some(self.game_start_time.elapsed().as_secs());
+            self.stats.longest_chain = self
+                .players
+                .iter()
+                .map(|player| player.score_chain.max_chain)
+                .max()
+                .unwrap_or(0);
+            self.save_run_record();
+
+            // A qualifying score asks for initials before the game over screen
+            self.screens
+                .replace(if self.high_scores.qualifies(self.get_score()) {
+                    GameState::EnterInitials
+                } else {
+                    GameState::GameOver
+                });
+        }
+    }
+
+    /// Writes this run's stats to the runs folder for later analysis
+    fn save_run_record(&self) {
+        let Some(dir) = RunRecord::default_dir() else {
+            return;
+        };
+
+        let record = RunRecord {
+            timestamp: unix_timestamp(),
+            seed: self.seed,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            ship: self
+                .players
+                .iter()
+                .map(|player| player.get_ship().name)
+                .collect::<Vec<_>>()
+                .join(" + "),
+            score: self.get_score(),
+            time_survived_secs: self.final_time_secs.unwrap_or(0),
+            stats: self.stats.clone(),
+        };
+        // Losing the record shouldn't interrupt the game
+        let _ = record.save_in(&dir);
+    }
+
+    /// Adds this run to the high-score table and saves it
+    fn record_high_score(&mut self) {
+        self.last_rank = self.high_scores.insert(HighScoreEntry {
+            initials: self.initials.get_initials(),
+            score: self.get_score(),
+            timestamp: unix_timestamp(),
+            time_survived_secs: self.final_time_secs.unwrap_or(0),
+            seed: self.seed,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        });
+
+        // Failing to save shouldn't end the game; the score still shows for this session
+        if let Some(path) = &self.high_scores_path {
+            let _ = self.high_scores.save(path);
         }
+
+        self.screens.replace(GameState::GameOver);
     }
 
     fn spawn_formation(&mut self) {
-        let mut rng = rand::rng();
+        let rng = &mut self.rng;
 
         // Randomly select a formation type
         let formation_type = match rng.random_range(0..4) {
@@ -322,6 +1059,8 @@ impl App {
         let center_y = 5;
 
         let formation_id = self.formations.len();
+        // Formation ids are reused, so forget any earlier formation with this id
+        self.broken_formations.retain(|&id| id != formation_id);
         let mut formation = Formation::new(center_x, center_y, formation_type);
 
         // Get positions and create enemies
@@ -339,60 +1078,238 @@ impl App {
             let enemy_idx = self.enemies.len();
             formation.enemy_indices.push(enemy_idx);
 
-            self.enemies.push(Enemy::new_in_formation(
+            let mut enemy = Enemy::new_in_formation(x, y, enemy_type, formation_id, offset);
+            enemy.id = self.next_enemy_id;
+            self.next_enemy_id = self.next_enemy_id.wrapping_add(1);
+            self.enemies.push(enemy);
+        }
+
+        self.formations.push(formation);
+    }
+
+    /// Damage number popping up just above a hit enemy
+    fn damage_number(enemy: &Enemy, damage: u8) -> FloatingText {
+        FloatingText::new(
+            enemy.x + enemy.get_width() / 2,
+            enemy.y.saturating_sub(1),
+            damage.to_string(),
+            FloatingTextKind::Damage,
+        )
+    }
+
+    /// Removes enemies by index, rolling for pickup drops from the ones that were destroyed
+    fn remove_enemies(&mut self, mut indices: Vec<usize>) {
+        // Remove in reverse order to avoid index issues
+        indices.sort_unstable();
+        indices.reverse();
+        indices.dedup();
+        for idx in indices {
+            if idx >= self.enemies.len() {
+                continue;
+            }
+
+            let enemy = self.enemies.remove(idx);
+            if enemy.is_alive() {
+                // Enemies that escaped or rammed the ship spoil the formation clear
+                if let Some(formation_id) = enemy.formation_id {
+                    self.broken_formations.push(formation_id);
+                }
+                continue;
+            }
+
+            // The last kill of an untouched formation always drops something
+            let formation_cleared = enemy.formation_id.is_some_and(|id| {
+                !self.broken_formations.contains(&id)
+                    && !self.enemies.iter().any(|e| e.formation_id == Some(id))
+            });
+
+            // Score the kill for the player who finished it off, extending their chain
+            self.stats.record_kill(enemy.enemy_type);
+            let x = enemy.x + enemy.get_width() / 2;
+            let y = enemy.y + enemy.get_height() / 2;
+            let player = &mut self.players[enemy.last_hit_by.unwrap_or_default()];
+            let multiplier = player.score_chain.get_multiplier();
+            let points = player
+                .score_chain
+                .register_kill(enemy.get_points(), enemy.diving);
+            player.score += points;
+            self.floating_texts.push(FloatingText::new(
                 x,
                 y,
-                enemy_type,
-                formation_id,
-                offset,
+                format!("+{}", points),
+                FloatingTextKind::Score,
             ));
+            if player.score_chain.get_multiplier() > multiplier {
+                self.floating_texts.push(FloatingText::new(
+                    x,
+                    y.saturating_sub(1),
+                    format!("x{} CHAIN", player.score_chain.get_multiplier()),
+                    FloatingTextKind::Chain,
+                ));
+            }
+
+            if formation_cleared {
+                let bonus = player.score_chain.formation_bonus();
+                player.score += bonus;
+                self.floating_texts.push(FloatingText::new(
+                    x,
+                    y.saturating_sub(2),
+                    format!("FORMATION +{}", bonus),
+                    FloatingTextKind::Bonus,
+                ));
+            }
+
+            if let Some(kind) = self
+                .drop_table
+                .roll(&enemy, formation_cleared, &mut self.rng)
+            {
+                // Drop from the enemy's death position
+                self.pickups.push(Pickup::new(x, y, kind));
+            }
         }
+    }
 
-        self.formations.push(formation);
+    /// Player projectiles cancelling or deflecting enemy projectiles
+    fn check_projectile_collisions(&mut self) {
+        let mut projectiles_to_remove = Vec::new();
+        // Deflected projectile indices with the player who batted them back
+        let mut projectiles_to_deflect: Vec<(usize, usize)> = Vec::new();
+
+        for (p_idx, projectile) in self.projectiles.iter().enumerate() {
+            let Some(player) = projectile.owner.get_player_id() else {
+                continue;
+            };
+
+            for (e_idx, enemy_projectile) in self.projectiles.iter().enumerate() {
+                if projectiles_to_remove.contains(&e_idx)
+                    || projectiles_to_deflect.iter().any(|&(idx, _)| idx == e_idx)
+                    || !projectile.intersects(enemy_projectile)
+                {
+                    continue;
+                }
+
+                match projectile
+                    .interaction_with(enemy_projectile, &self.shootable_enemy_projectiles)
+                {
+                    ProjectileInteraction::Deflect => {
+                        // Slashes keep going and can deflect several bullets
+                        projectiles_to_deflect.push((e_idx, player));
+                    }
+                    ProjectileInteraction::Cancel => {
+                        self.particles.extend(self.settings.particles.thin(
+                            create_explosion_particles(enemy_projectile.x, enemy_projectile.y),
+                        ));
+                        projectiles_to_remove.push(p_idx);
+                        projectiles_to_remove.push(e_idx);
+                        break;
+                    }
+                    ProjectileInteraction::None => {}
+                }
+            }
+        }
+
+        // Deflected bullets change sides; the slash was already counted as the shot
+        for (idx, player) in projectiles_to_deflect {
+            self.projectiles[idx].deflect(player);
+        }
+
+        // Remove in reverse order to avoid index issues
+        projectiles_to_remove.sort_unstable();
+        projectiles_to_remove.reverse();
+        projectiles_to_remove.dedup();
+        for idx in projectiles_to_remove {
+            if idx < self.projectiles.len() {
+                self.projectiles.remove(idx);
+            }
+        }
     }
 
-    fn spawn_pickup(&mut self) {
-        let mut rng = rand::rng();
+    /// Piercing slashes hitting every enemy in their arc once
+    fn check_slash_hits(&mut self) {
+        let mut enemies_to_remove = Vec::new();
 
-        // Randomly select a weapon type
-        let weapon_type = match rng.random_range(0..4) {
-            0 => WeaponType::BasicGun,
-            1 => WeaponType::Sword,
-            2 => WeaponType::Bug,
-            _ => WeaponType::Bomber,
-        };
+        for projectile in self.projectiles.iter_mut() {
+            let Some(player) = projectile.owner.get_player_id() else {
+                continue;
+            };
+            if !projectile.pierces() {
+                continue;
+            }
 
-        // Pickup coordinates are relative to game area
-        // Game area width = screen_width - (edge_width * 2) - 2 (for borders)
-        let game_area_width = self.screen_width.saturating_sub(self.edge_width * 2 + 2);
-        let min_x = 3;
-        let max_x = game_area_width.saturating_sub(3);
-        let x = rng.random_range(min_x..max_x.max(min_x + 1));
+            for (e_idx, enemy) in self.enemies.iter_mut().enumerate() {
+                if !enemy.is_alive() || projectile.hit_enemies.contains(&enemy.id) {
+                    continue;
+                }
+
+                let enemy_width = enemy.get_width();
+                let enemy_height = enemy.get_height();
 
-        self.pickups.push(Pickup::new(x, 3, weapon_type));
+                if projectile.overlaps_rect(enemy.x, enemy.y, enemy_width, enemy_height) {
+                    enemy.take_damage_from(projectile.damage, player);
+                    if projectile.hit_enemies.is_empty() {
+                        self.stats.record_hit();
+                    }
+                    projectile.hit_enemies.push(enemy.id);
+                    self.floating_texts
+                        .push(Self::damage_number(enemy, projectile.damage));
+
+                    if !enemy.is_alive() {
+                        // Create particles at enemy death location
+                        let enemy_center_x = enemy.x + enemy_width / 2;
+                        let enemy_center_y = enemy.y + enemy_height / 2;
+                        let death_particles =
+                            create_explosion_particles(enemy_center_x, enemy_center_y);
+                        self.particles
+                            .extend(self.settings.particles.thin(death_particles));
+                        enemies_to_remove.push(e_idx);
+                    }
+                }
+            }
+        }
+
+        self.remove_enemies(enemies_to_remove);
     }
 
     fn check_collisions(&mut self) {
+        let health_before: Vec<u8> = self.players.iter().map(|player| player.health).collect();
+
         // Player projectiles hitting enemies
         let mut projectiles_to_remove = Vec::new();
         let mut enemies_to_remove = Vec::new();
+        let mut new_projectiles = Vec::new();
 
         for (p_idx, projectile) in self.projectiles.iter().enumerate() {
-            if projectile.owner == ProjectileOwner::Player {
+            if let Some(player) = projectile.owner.get_player_id() {
                 // Check if bomber projectile lifetime expired (explodes)
-                if projectile.projectile_type == ProjectileType::BomberProjectile
-                    && projectile.lifetime == Some(0)
-                {
+                if projectile.is_exploding() {
                     // Explosion! Deal AoE damage to all enemies in radius
-                    const EXPLOSION_RADIUS: u16 = 8;
                     const EXPLOSION_DAMAGE: u8 = 25;
+                    let explosion_radius = projectile.radius as i32;
 
                     // Create explosion particle effect
                     let explosion_particles =
                         create_explosion_particles(projectile.x, projectile.y);
-                    self.particles.extend(explosion_particles);
+                    self.particles
+                        .extend(self.settings.particles.thin(explosion_particles));
+
+                    // Clear enemy projectiles caught in the blast
+                    for (other_idx, other) in self.projectiles.iter().enumerate() {
+                        if other.owner == ProjectileOwner::Enemy {
+                            let dx = (projectile.x as i32 - other.x as i32).abs();
+                            let dy = (projectile.y as i32 - other.y as i32).abs();
+                            if (dx * dx + dy * dy) <= explosion_radius * explosion_radius {
+                                projectiles_to_remove.push(other_idx);
+                            }
+                        }
+                    }
 
+                    let mut blast_hit = false;
                     for (e_idx, enemy) in self.enemies.iter_mut().enumerate() {
+                        // Skip enemies already destroyed by an earlier blast this frame
+                        if !enemy.is_alive() {
+                            continue;
+                        }
+
                         // Calculate distance between explosion center and enemy center
                         let enemy_center_x = enemy.x + enemy.get_width() / 2;
                         let enemy_center_y = enemy.y + enemy.get_height() / 2;
@@ -401,26 +1318,39 @@ impl App {
                         let dy = (projectile.y as i32 - enemy_center_y as i32).abs();
 
                         // Simple circle collision (using squared distance to avoid sqrt)
-                        if (dx * dx + dy * dy)
-                            <= (EXPLOSION_RADIUS as i32 * EXPLOSION_RADIUS as i32)
-                        {
-                            enemy.take_damage(EXPLOSION_DAMAGE);
+                        if (dx * dx + dy * dy) <= explosion_radius * explosion_radius {
+                            enemy.take_damage_from(EXPLOSION_DAMAGE, player);
+                            blast_hit = true;
+                            self.floating_texts
+                                .push(Self::damage_number(enemy, EXPLOSION_DAMAGE));
 
                             if !enemy.is_alive() {
                                 // Create particles at enemy death location
                                 let death_particles =
                                     create_explosion_particles(enemy_center_x, enemy_center_y);
-                                self.particles.extend(death_particles);
-
-                                self.score += enemy.get_points();
+                                self.particles
+                                    .extend(self.settings.particles.thin(death_particles));
                                 enemies_to_remove.push(e_idx);
                             }
                         }
                     }
+                    if blast_hit && !projectile.deflected {
+                        self.stats.record_hit();
+                    }
+                    // Cluster bombs scatter smaller bombs, each counted as a shot of its own
+                    let bomblets = projectile.spawn_bomblets();
+                    self.stats
+                        .record_shots(WeaponType::Bomber, bomblets.len() as u32);
+                    new_projectiles.extend(bomblets);
                     projectiles_to_remove.push(p_idx);
                     continue;
                 }
 
+                // Piercing projectiles are resolved in check_slash_hits
+                if projectile.pierces() {
+                    continue;
+                }
+
                 // Regular collision detection for non-bomber projectiles
                 for (e_idx, enemy) in self.enemies.iter_mut().enumerate() {
                     // Bounding box collision detection for larger sprites
@@ -432,8 +1362,14 @@ impl App {
                         && projectile.y >= enemy.y
                         && projectile.y < enemy.y + enemy_height
                     {
-                        enemy.take_damage(projectile.damage);
+                        enemy.take_damage_from(projectile.damage, player);
                         projectiles_to_remove.push(p_idx);
+                        // Deflected bullets weren't counted as shots, so their hits aren't either
+                        if !projectile.deflected {
+                            self.stats.record_hit();
+                        }
+                        self.floating_texts
+                            .push(Self::damage_number(enemy, projectile.damage));
 
                         if !enemy.is_alive() {
                             // Create particles at enemy death location
@@ -441,9 +1377,8 @@ impl App {
                             let enemy_center_y = enemy.y + enemy_height / 2;
                             let death_particles =
                                 create_explosion_particles(enemy_center_x, enemy_center_y);
-                            self.particles.extend(death_particles);
-
-                            self.score += enemy.get_points();
+                            self.particles
+                                .extend(self.settings.particles.thin(death_particles));
                             enemies_to_remove.push(e_idx);
                         }
                         break;
@@ -452,48 +1387,87 @@ impl App {
             }
         }
 
-        // Enemy projectiles hitting player
+        // Enemy projectiles hitting a ship, only ever the first one they reach
         for (p_idx, projectile) in self.projectiles.iter().enumerate() {
             if projectile.owner == ProjectileOwner::Enemy {
-                let player_width = self.player.get_width();
-                let player_height = self.player.get_height();
-
-                if projectile.x >= self.player.x
-                    && projectile.x < self.player.x + player_width
-                    && projectile.y >= self.player.y
-                    && projectile.y < self.player.y + player_height
-                {
-                    self.player.take_damage(projectile.damage);
+                let hit = self.players.iter_mut().find(|player| {
+                    player.is_alive()
+                        && projectile.x >= player.x
+                        && projectile.x < player.x + player.get_width()
+                        && projectile.y >= player.y
+                        && projectile.y < player.y + player.get_height()
+                });
+
+                if let Some(player) = hit {
+                    let health = player.health;
+                    player.take_damage(projectile.damage);
+                    self.stats
+                        .record_damage(DamageSource::EnemyFire, health - player.health);
                     projectiles_to_remove.push(p_idx);
                 }
             }
         }
 
-        // Enemies colliding with player
+        // Enemies colliding with a ship
         for (e_idx, enemy) in self.enemies.iter().enumerate() {
             let enemy_width = enemy.get_width();
             let enemy_height = enemy.get_height();
-            let player_width = self.player.get_width();
-            let player_height = self.player.get_height();
 
             // Check if bounding boxes overlap
-            if enemy.x < self.player.x + player_width
-                && enemy.x + enemy_width > self.player.x
-                && enemy.y < self.player.y + player_height
-                && enemy.y + enemy_height > self.player.y
-            {
+            let hit = self.players.iter_mut().find(|player| {
+                player.is_alive()
+                    && enemy.x < player.x + player.get_width()
+                    && enemy.x + enemy_width > player.x
+                    && enemy.y < player.y + player.get_height()
+                    && enemy.y + enemy_height > player.y
+            });
+
+            if let Some(player) = hit {
                 // Create particles at collision point
                 let enemy_center_x = enemy.x + enemy_width / 2;
                 let enemy_center_y = enemy.y + enemy_height / 2;
                 let collision_particles =
                     create_explosion_particles(enemy_center_x, enemy_center_y);
-                self.particles.extend(collision_particles);
+                self.particles
+                    .extend(self.settings.particles.thin(collision_particles));
 
-                self.player.take_damage(20);
+                let health = player.health;
+                player.take_damage(COLLISION_DAMAGE);
+                self.stats
+                    .record_damage(DamageSource::Collision, health - player.health);
                 enemies_to_remove.push(e_idx);
             }
         }
 
+        for (player, &before) in self.players.iter_mut().zip(&health_before) {
+            if player.health >= before {
+                continue;
+            }
+
+            // Getting hit breaks the kill chain
+            player.score_chain.reset();
+            if self.settings.screen_shake {
+                self.shake_frames = SHAKE_FRAMES;
+            }
+            let center_x = player.x + player.get_width() / 2;
+            self.floating_texts.push(FloatingText::new(
+                center_x,
+                player.y.saturating_sub(1),
+                format!("-{}", before - player.health),
+                FloatingTextKind::PlayerDamage,
+            ));
+
+            // A downed ship breaks apart while the other player flies on
+            if !player.is_alive() {
+                let center_y = player.y + player.get_height() / 2;
+                self.particles.extend(
+                    self.settings
+                        .particles
+                        .thin(create_explosion_particles(center_x, center_y)),
+                );
+            }
+        }
+
         // Remove in reverse order to avoid index issues
         projectiles_to_remove.sort_unstable();
         projectiles_to_remove.reverse();
@@ -504,30 +1478,54 @@ impl App {
             }
         }
 
-        enemies_to_remove.sort_unstable();
-        enemies_to_remove.reverse();
-        enemies_to_remove.dedup();
-        for idx in enemies_to_remove {
-            if idx < self.enemies.len() {
-                self.enemies.remove(idx);
-            }
-        }
+        self.projectiles.extend(new_projectiles);
 
-        // Player collecting pickups
+        self.remove_enemies(enemies_to_remove);
+
+        // Ships collecting pickups, which go to whichever ship reaches them first
         let mut pickups_to_remove = Vec::new();
         for (idx, pickup) in self.pickups.iter().enumerate() {
-            let player_width = self.player.get_width();
-            let player_height = self.player.get_height();
             let pickup_width = pickup.get_width();
             let pickup_height = pickup.get_height();
 
             // Check if bounding boxes overlap
-            if pickup.x < self.player.x + player_width
-                && pickup.x + pickup_width > self.player.x
-                && pickup.y < self.player.y + player_height
-                && pickup.y + pickup_height > self.player.y
-            {
-                self.player.change_weapon(pickup.weapon_type);
+            let collector = self.players.iter_mut().find(|player| {
+                player.is_alive()
+                    && pickup.x < player.x + player.get_width()
+                    && pickup.x + pickup_width > player.x
+                    && pickup.y < player.y + player.get_height()
+                    && pickup.y + pickup_height > player.y
+            });
+
+            if let Some(player) = collector {
+                self.stats.record_pickup(pickup.kind);
+                match pickup.kind {
+                    PickupKind::Weapon(weapon_type) => player.collect_weapon(weapon_type),
+                    PickupKind::Repair => {
+                        if player.get_ship().passive == ShipPassive::Salvage {
+                            player.heal(REPAIR_AMOUNT * 2);
+                        } else {
+                            player.heal(REPAIR_AMOUNT);
+                        }
+                    }
+                    PickupKind::Shield => {
+                        player.activate_shield(SHIELD_DURATION_FRAMES, SHIELD_HITS)
+                    }
+                    PickupKind::Bomb => {
+                        // A full bomb stock turns extra bombs into points
+                        if !player.add_bomb() {
+                            player.score += MEDAL_POINTS;
+                        }
+                    }
+                    PickupKind::Medal => player.score += MEDAL_POINTS,
+                    PickupKind::SpeedBoost => player.activate_speed_boost(SPEED_BOOST_FRAMES),
+                }
+                self.floating_texts.push(FloatingText::new(
+                    pickup.x,
+                    pickup.y.saturating_sub(1),
+                    pickup.kind.get_label(),
+                    FloatingTextKind::Pickup,
+                ));
                 pickups_to_remove.push(idx);
             }
         }
@@ -543,3 +1541,61 @@ impl App {
         }
     }
 }
+
+/// Seconds since the Unix epoch, 0 if the clock is before it
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A Basic enemy centred on player one's laser column, `rows` above the ship
+    fn enemy_above_player(app: &mut App, rows: u16, health: u8) -> u32 {
+        let player = &app.players[0];
+        let x = player.x + player.get_width() / 2 - 3;
+        let mut enemy = Enemy::new_in_formation(x, player.y - rows, EnemyType::Basic, 0, (0, 0));
+        let id = app.next_enemy_id;
+        app.next_enemy_id += 1;
+        enemy.id = id;
+        enemy.health = health;
+        app.enemies.push(enemy);
+        id
+    }
+
+    #[test]
+    fn test_hits_never_outnumber_shots() {
+        let mut app = App::new();
+        app.enemies.clear();
+
+        // One laser burst kills the nearer enemy, then strikes the one behind it
+        app.players[0].change_weapon(WeaponType::Laser);
+        enemy_above_player(&mut app, 10, 1);
+        let far = enemy_above_player(&mut app, 20, 15);
+        app.fire(0);
+        app.players[0].update_cooldown();
+        app.fire(0);
+        assert_eq!(app.enemies.len(), 1);
+        assert_eq!(app.enemies[0].id, far);
+        assert!(app.enemies[0].health < 15);
+        assert_eq!(app.stats.get_shots_fired(), 1);
+        assert_eq!(app.stats.hits, 1);
+
+        // A bullet batted back by a sword kills without counting as a shot or a hit
+        let enemy = &app.enemies[0];
+        let mut bullet = Projectile::new(enemy.x + 1, enemy.y + 1, ProjectileOwner::Enemy);
+        bullet.damage = enemy.health;
+        bullet.deflect(0);
+        app.projectiles = vec![bullet];
+        app.check_collisions();
+        assert!(app.enemies.is_empty());
+        assert_eq!(app.stats.get_kills(), 2);
+        assert_eq!(app.stats.get_shots_fired(), 1);
+        assert_eq!(app.stats.hits, 1);
+        assert_eq!(app.stats.get_accuracy(), 100.0);
+    }
+}
@@ -2,12 +2,18 @@ use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source, source::Buf
 use std::fs::File;
 use std::io::BufReader;
 
+/// Playback volume of the fire sound at full master volume
+const FIRE_SOUND_VOLUME: f32 = 0.01;
+
 /// Audio manager for playing sound effects
 pub struct AudioManager {
-    _stream: OutputStream,
-    stream_handle: OutputStreamHandle,
+    /// Output device (None if there's no audio device, e.g. in tests)
+    _stream: Option<OutputStream>,
+    stream_handle: Option<OutputStreamHandle>,
     /// Pre-loaded and buffered fire sound (None if loading failed)
     fire_sound: Option<Buffered<Decoder<BufReader<File>>>>,
+    /// Master volume from 0.0 (silent) to 1.0
+    volume: f32,
 }
 
 impl AudioManager {
@@ -21,23 +27,31 @@ impl AudioManager {
         let fire_sound = Some(source.buffered());
 
         Ok(Self {
-            _stream: stream,
-            stream_handle,
+            _stream: Some(stream),
+            stream_handle: Some(stream_handle),
             fire_sound,
+            volume: 1.0,
         })
     }
 
-    /// Play the weapon fire sound at default volume (30%)
+    /// Sets the master volume, from 0.0 (silent) to 1.0
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.clamp(0.0, 1.0);
+    }
+
+    /// Play the weapon fire sound scaled by the master volume
     pub fn play_fire_sound(&self) {
-        self.play_fire_sound_volume(0.01);
+        if self.volume > 0.0 {
+            self.play_fire_sound_volume(FIRE_SOUND_VOLUME * self.volume);
+        }
     }
 
     /// Play the weapon fire sound at a specific volume
     pub fn play_fire_sound_volume(&self, volume: f32) {
         // Only play if the sound was successfully loaded
-        if let Some(fire_sound) = &self.fire_sound {
+        if let (Some(fire_sound), Some(stream_handle)) = (&self.fire_sound, &self.stream_handle) {
             // Ignore errors for sound playback - don't want to crash the game
-            if let Ok(sink) = Sink::try_new(&self.stream_handle) {
+            if let Ok(sink) = Sink::try_new(stream_handle) {
                 sink.set_volume(volume);
                 // Clone the buffered source (fast - just clones references)
                 sink.append(fire_sound.clone());
@@ -54,14 +68,14 @@ impl Default for AudioManager {
             eprintln!("Warning: Failed to initialize audio: {}", err);
             eprintln!("Continuing without audio...");
 
-            // Fallback: create audio manager without sound
-            let (stream, stream_handle) =
-                OutputStream::try_default().expect("Failed to create audio output stream");
+            // Fallback: create audio manager without sound, or without any output device
+            let (stream, stream_handle) = OutputStream::try_default().ok().unzip();
 
             Self {
                 _stream: stream,
                 stream_handle,
                 fire_sound: None, // No sound loaded - will silently fail to play
+                volume: 1.0,
             }
         })
     }
@@ -0,0 +1,770 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, ModifierKeyCode};
+use serde::de::value::{Error as ValueError, StrDeserializer};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::input::InputAction;
+
+/// Game actions that can be bound to keys, in the order shown on the controls screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Control {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Fire,
+    Focus,
+    Dash,
+    Bomb,
+    ToggleMovement,
+    ToggleFloatingText,
+    Pause,
+    Restart,
+    Quit,
+    /// Player two's controls, only read in co-op
+    P2MoveUp,
+    P2MoveDown,
+    P2MoveLeft,
+    P2MoveRight,
+    P2Fire,
+    P2Focus,
+    P2Dash,
+    P2Bomb,
+}
+
+impl Control {
+    pub const ALL: [Control; 21] = [
+        Control::MoveUp,
+        Control::MoveDown,
+        Control::MoveLeft,
+        Control::MoveRight,
+        Control::Fire,
+        Control::Focus,
+        Control::Dash,
+        Control::Bomb,
+        Control::ToggleMovement,
+        Control::ToggleFloatingText,
+        Control::Pause,
+        Control::Restart,
+        Control::Quit,
+        Control::P2MoveUp,
+        Control::P2MoveDown,
+        Control::P2MoveLeft,
+        Control::P2MoveRight,
+        Control::P2Fire,
+        Control::P2Focus,
+        Control::P2Dash,
+        Control::P2Bomb,
+    ];
+
+    /// Description shown on the controls screen
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            Control::MoveUp => "Move up",
+            Control::MoveDown => "Move down",
+            Control::MoveLeft => "Move left",
+            Control::MoveRight => "Move right",
+            Control::Fire => "Fire",
+            Control::Focus => "Focus (hold)",
+            Control::Dash => "Dash",
+            Control::Bomb => "Smart bomb",
+            Control::ToggleMovement => "Toggle handling",
+            Control::ToggleFloatingText => "Toggle score popups",
+            Control::Pause => "Pause",
+            Control::Restart => "Restart (game over)",
+            Control::Quit => "Quit",
+            Control::P2MoveUp => "P2 move up",
+            Control::P2MoveDown => "P2 move down",
+            Control::P2MoveLeft => "P2 move left",
+            Control::P2MoveRight => "P2 move right",
+            Control::P2Fire => "P2 fire",
+            Control::P2Focus => "P2 focus (hold)",
+            Control::P2Dash => "P2 dash",
+            Control::P2Bomb => "P2 smart bomb",
+        }
+    }
+
+    /// Short name used in the in-game key hint
+    pub fn get_hint_label(&self) -> &'static str {
+        match self {
+            Control::MoveUp | Control::MoveDown | Control::MoveLeft | Control::MoveRight => "Move",
+            Control::Fire => "Fire",
+            Control::Focus => "Focus",
+            Control::Dash => "Dash",
+            Control::Bomb => "Bomb",
+            Control::ToggleMovement => "Handling",
+            Control::ToggleFloatingText => "Popups",
+            Control::Pause => "Pause",
+            Control::Restart => "Restart",
+            Control::Quit => "Quit",
+            Control::P2MoveUp
+            | Control::P2MoveDown
+            | Control::P2MoveLeft
+            | Control::P2MoveRight => "P2 Move",
+            Control::P2Fire => "P2 Fire",
+            Control::P2Focus => "P2 Focus",
+            Control::P2Dash => "P2 Dash",
+            Control::P2Bomb => "P2 Bomb",
+        }
+    }
+
+    /// The direction a movement control cancels when pressed
+    pub fn get_opposite(&self) -> Option<Control> {
+        match self {
+            Control::MoveUp => Some(Control::MoveDown),
+            Control::MoveDown => Some(Control::MoveUp),
+            Control::MoveLeft => Some(Control::MoveRight),
+            Control::MoveRight => Some(Control::MoveLeft),
+            Control::P2MoveUp => Some(Control::P2MoveDown),
+            Control::P2MoveDown => Some(Control::P2MoveUp),
+            Control::P2MoveLeft => Some(Control::P2MoveRight),
+            Control::P2MoveRight => Some(Control::P2MoveLeft),
+            _ => None,
+        }
+    }
+
+    /// Controls belonging to the second ship in co-op
+    pub fn is_player_two(&self) -> bool {
+        matches!(
+            self,
+            Control::P2MoveUp
+                | Control::P2MoveDown
+                | Control::P2MoveLeft
+                | Control::P2MoveRight
+                | Control::P2Fire
+                | Control::P2Focus
+                | Control::P2Dash
+                | Control::P2Bomb
+        )
+    }
+
+    /// Player one's control for the same job, e.g. Fire for P2Fire
+    fn get_player_one_control(&self) -> Control {
+        match self {
+            Control::P2MoveUp => Control::MoveUp,
+            Control::P2MoveDown => Control::MoveDown,
+            Control::P2MoveLeft => Control::MoveLeft,
+            Control::P2MoveRight => Control::MoveRight,
+            Control::P2Fire => Control::Fire,
+            Control::P2Focus => Control::Focus,
+            Control::P2Dash => Control::Dash,
+            Control::P2Bomb => Control::Bomb,
+            control => *control,
+        }
+    }
+
+    fn is_movement(&self) -> bool {
+        matches!(
+            self,
+            Control::MoveUp
+                | Control::MoveDown
+                | Control::MoveLeft
+                | Control::MoveRight
+                | Control::P2MoveUp
+                | Control::P2MoveDown
+                | Control::P2MoveLeft
+                | Control::P2MoveRight
+        )
+    }
+
+    /// Whether two controls may be bound to the same key without a conflict
+    /// Player two's keys only take over from player one's matching controls in co-op, so by
+    /// default the arrows move the ship alone and player two in co-op
+    pub fn can_share_keys(&self, other: Control) -> bool {
+        let same_role = (self.is_movement() && other.is_movement())
+            || self.get_player_one_control() == other.get_player_one_control();
+        same_role && self.is_player_two() != other.is_player_two()
+    }
+
+    /// Controls that stay active for as long as the key is held
+    pub fn is_held(&self) -> bool {
+        matches!(
+            self,
+            Control::MoveUp
+                | Control::MoveDown
+                | Control::MoveLeft
+                | Control::MoveRight
+                | Control::Fire
+                | Control::Focus
+                | Control::P2MoveUp
+                | Control::P2MoveDown
+                | Control::P2MoveLeft
+                | Control::P2MoveRight
+                | Control::P2Fire
+                | Control::P2Focus
+        )
+    }
+
+    pub fn get_action(&self) -> InputAction {
+        match self {
+            Control::MoveUp => InputAction::MoveUp,
+            Control::MoveDown => InputAction::MoveDown,
+            Control::MoveLeft => InputAction::MoveLeft,
+            Control::MoveRight => InputAction::MoveRight,
+            Control::Fire => InputAction::Fire,
+            Control::Focus => InputAction::Focus,
+            Control::Dash => InputAction::Dash,
+            Control::Bomb => InputAction::Bomb,
+            Control::ToggleMovement => InputAction::ToggleMovement,
+            Control::ToggleFloatingText => InputAction::ToggleFloatingText,
+            Control::Pause => InputAction::Pause,
+            Control::Restart => InputAction::Restart,
+            Control::Quit => InputAction::Quit,
+            Control::P2MoveUp => InputAction::P2MoveUp,
+            Control::P2MoveDown => InputAction::P2MoveDown,
+            Control::P2MoveLeft => InputAction::P2MoveLeft,
+            Control::P2MoveRight => InputAction::P2MoveRight,
+            Control::P2Fire => InputAction::P2Fire,
+            Control::P2Focus => InputAction::P2Focus,
+            Control::P2Dash => InputAction::P2Dash,
+            Control::P2Bomb => InputAction::P2Bomb,
+        }
+    }
+}
+
+/// Keys with a name, as written in the config file
+const NAMED_KEYS: &[(&str, KeyCode)] = &[
+    ("Space", KeyCode::Char(' ')),
+    ("Enter", KeyCode::Enter),
+    ("Tab", KeyCode::Tab),
+    ("Backspace", KeyCode::Backspace),
+    ("Esc", KeyCode::Esc),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Insert", KeyCode::Insert),
+    ("Delete", KeyCode::Delete),
+    ("LShift", KeyCode::Modifier(ModifierKeyCode::LeftShift)),
+    ("RShift", KeyCode::Modifier(ModifierKeyCode::RightShift)),
+    ("LCtrl", KeyCode::Modifier(ModifierKeyCode::LeftControl)),
+    ("RCtrl", KeyCode::Modifier(ModifierKeyCode::RightControl)),
+    ("LAlt", KeyCode::Modifier(ModifierKeyCode::LeftAlt)),
+    ("RAlt", KeyCode::Modifier(ModifierKeyCode::RightAlt)),
+];
+
+/// A key plus the Ctrl/Alt modifiers that must be held with it
+/// Letters are stored lowercase and Shift is ignored, so `W` and `w` are the same key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code: normalize_code(code),
+            modifiers: modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT),
+        }
+    }
+
+    /// A plain key with no modifiers
+    pub fn key(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    /// The binding for a key press, None for keys that can't be bound
+    pub fn from_event(key_event: &KeyEvent) -> Option<Self> {
+        let binding = Self::new(key_event.code, key_event.modifiers);
+        binding.get_key_name().map(|_| binding)
+    }
+
+    fn get_key_name(&self) -> Option<String> {
+        match self.code {
+            KeyCode::Char(' ') => Some("Space".to_string()),
+            KeyCode::Char(c) if !c.is_control() => Some(c.to_uppercase().to_string()),
+            KeyCode::F(n) => Some(format!("F{}", n)),
+            code => NAMED_KEYS
+                .iter()
+                .find(|(_, named)| *named == code)
+                .map(|(name, _)| name.to_string()),
+        }
+    }
+}
+
+/// Letters match regardless of case
+fn normalize_code(code: KeyCode) -> KeyCode {
+    match code {
+        KeyCode::Char(c) => KeyCode::Char(c.to_ascii_lowercase()),
+        code => code,
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        write!(
+            f,
+            "{}",
+            self.get_key_name().unwrap_or_else(|| "?".to_string())
+        )
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = String;
+
+    /// Parses names like `w`, `Space`, `F5` or `Ctrl+Up`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut key = text;
+        // A lone "+" is the plus key rather than a modifier separator
+        while let Some((modifier, rest)) = key.split_once('+').filter(|(_, rest)| !rest.is_empty())
+        {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" => modifiers |= KeyModifiers::CONTROL,
+                "alt" => modifiers |= KeyModifiers::ALT,
+                _ => return Err(format!("unknown modifier '{}' in '{}'", modifier, text)),
+            }
+            key = rest;
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => {
+                let function_key = key
+                    .strip_prefix(['F', 'f'])
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .filter(|n| (1..=12).contains(n));
+                match function_key {
+                    Some(n) => KeyCode::F(n),
+                    None => NAMED_KEYS
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                        .map(|(_, code)| *code)
+                        .ok_or_else(|| format!("unknown key '{}'", text))?,
+                }
+            }
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl TryFrom<String> for KeyBinding {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+impl From<KeyBinding> for String {
+    fn from(binding: KeyBinding) -> Self {
+        binding.to_string()
+    }
+}
+
+/// Keys joined for display, or `-` for none
+fn join_keys(keys: &[KeyBinding]) -> String {
+    if keys.is_empty() {
+        return "-".to_string();
+    }
+    keys.iter()
+        .map(KeyBinding::to_string)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Which keys trigger each control; a control can have several keys
+/// Stored in the config as a table of control name to key names, where any control left
+/// out keeps its default keys. Unknown control or key names are skipped rather than
+/// failing the whole config, and noted in the load warnings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    from = "BTreeMap<String, Vec<String>>",
+    into = "BTreeMap<Control, Vec<KeyBinding>>"
+)]
+pub struct KeyBindings {
+    keys: BTreeMap<Control, Vec<KeyBinding>>,
+    /// Entries of the config table that couldn't be read
+    load_warnings: Vec<String>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let char_key = |c| KeyBinding::key(KeyCode::Char(c));
+        let keys = Control::ALL
+            .into_iter()
+            .map(|control| {
+                let keys = match control {
+                    Control::MoveUp => vec![char_key('w'), KeyBinding::key(KeyCode::Up)],
+                    Control::MoveDown => vec![char_key('s'), KeyBinding::key(KeyCode::Down)],
+                    Control::MoveLeft => vec![char_key('a'), KeyBinding::key(KeyCode::Left)],
+                    Control::MoveRight => vec![char_key('d'), KeyBinding::key(KeyCode::Right)],
+                    Control::Fire => vec![char_key(' ')],
+                    Control::Focus => vec![
+                        KeyBinding::key(KeyCode::Modifier(ModifierKeyCode::LeftShift)),
+                        KeyBinding::key(KeyCode::Modifier(ModifierKeyCode::RightShift)),
+                    ],
+                    Control::Dash => vec![char_key('e')],
+                    Control::Bomb => vec![char_key('b')],
+                    Control::ToggleMovement => vec![char_key('m')],
+                    Control::ToggleFloatingText => vec![char_key('t')],
+                    Control::Pause => vec![char_key('p')],
+                    Control::Restart => vec![char_key('r')],
+                    Control::Quit => vec![char_key('q')],
+                    Control::P2MoveUp => vec![KeyBinding::key(KeyCode::Up)],
+                    Control::P2MoveDown => vec![KeyBinding::key(KeyCode::Down)],
+                    Control::P2MoveLeft => vec![KeyBinding::key(KeyCode::Left)],
+                    Control::P2MoveRight => vec![KeyBinding::key(KeyCode::Right)],
+                    // Right Ctrl needs modifier key events, so Enter works everywhere
+                    Control::P2Fire => vec![
+                        KeyBinding::key(KeyCode::Enter),
+                        KeyBinding::key(KeyCode::Modifier(ModifierKeyCode::RightControl)),
+                    ],
+                    // Keys above the arrows, which Shift (for focus) doesn't change
+                    Control::P2Focus => vec![
+                        KeyBinding::key(KeyCode::Modifier(ModifierKeyCode::RightShift)),
+                        KeyBinding::key(KeyCode::Delete),
+                    ],
+                    Control::P2Dash => vec![KeyBinding::key(KeyCode::End)],
+                    Control::P2Bomb => vec![KeyBinding::key(KeyCode::PageDown)],
+                };
+                (control, keys)
+            })
+            .collect();
+        Self {
+            keys,
+            load_warnings: Vec::new(),
+        }
+    }
+}
+
+impl From<BTreeMap<Control, Vec<KeyBinding>>> for KeyBindings {
+    fn from(keys: BTreeMap<Control, Vec<KeyBinding>>) -> Self {
+        let mut bindings = Self::default();
+        bindings.keys.extend(keys);
+        bindings
+    }
+}
+
+impl From<BTreeMap<String, Vec<String>>> for KeyBindings {
+    fn from(table: BTreeMap<String, Vec<String>>) -> Self {
+        let mut keys = BTreeMap::new();
+        let mut load_warnings = Vec::new();
+        for (name, key_names) in table {
+            let control = Control::deserialize(StrDeserializer::<ValueError>::new(&name));
+            let Ok(control) = control else {
+                load_warnings.push(format!("unknown control '{}' in [bindings]", name));
+                continue;
+            };
+            // A control with a bad key keeps all of its default keys
+            match key_names.into_iter().map(|key| key.parse()).collect() {
+                Ok(bindings) => {
+                    keys.insert(control, bindings);
+                }
+                Err(err) => load_warnings.push(format!(
+                    "{} for {} in [bindings], keeping its default keys",
+                    err, name
+                )),
+            }
+        }
+
+        let mut bindings = Self::from(keys);
+        bindings.load_warnings = load_warnings;
+        bindings
+    }
+}
+
+impl From<KeyBindings> for BTreeMap<Control, Vec<KeyBinding>> {
+    fn from(bindings: KeyBindings) -> Self {
+        bindings.keys
+    }
+}
+
+impl KeyBindings {
+    /// Takes the problems found reading the config table, for reporting once at startup
+    pub fn take_load_warnings(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.load_warnings)
+    }
+
+    pub fn get_keys(&self, control: Control) -> &[KeyBinding] {
+        self.keys.get(&control).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Controls in the order keys are looked up: player two's come first in co-op and are
+    /// left out otherwise
+    fn get_lookup_order(co_op: bool) -> impl Iterator<Item = Control> {
+        let player_two = Control::ALL
+            .into_iter()
+            .filter(move |c| co_op && c.is_player_two());
+        let rest = Control::ALL.into_iter().filter(|c| !c.is_player_two());
+        player_two.chain(rest)
+    }
+
+    /// The control a key triggers; if the key is bound twice the earlier control wins
+    pub fn get_control(&self, binding: KeyBinding, co_op: bool) -> Option<Control> {
+        Self::get_lookup_order(co_op).find(|&control| self.get_keys(control).contains(&binding))
+    }
+
+    /// The control a released key was holding, whatever modifiers are still down
+    pub fn get_control_for_code(&self, code: KeyCode, co_op: bool) -> Option<Control> {
+        let code = normalize_code(code);
+        Self::get_lookup_order(co_op).find(|&control| {
+            self.get_keys(control)
+                .iter()
+                .any(|binding| binding.code == code)
+        })
+    }
+
+    /// The keys that actually trigger a control: in co-op player one loses any it shares
+    /// with player two
+    pub fn get_active_keys(&self, control: Control, co_op: bool) -> Vec<KeyBinding> {
+        self.get_keys(control)
+            .iter()
+            .copied()
+            .filter(|&binding| self.get_control(binding, co_op) == Some(control))
+            .collect()
+    }
+
+    /// Adds a key to a control, refusing (and naming the other control) if it's already taken
+    pub fn bind(&mut self, control: Control, binding: KeyBinding) -> Result<(), Control> {
+        let taken_by = Control::ALL.into_iter().find(|&other| {
+            other != control
+                && !other.can_share_keys(control)
+                && self.get_keys(other).contains(&binding)
+        });
+        if let Some(other) = taken_by {
+            return Err(other);
+        }
+
+        let keys = self.keys.entry(control).or_default();
+        if !keys.contains(&binding) {
+            keys.push(binding);
+        }
+        Ok(())
+    }
+
+    /// Removes the most recently added key from a control
+    pub fn unbind_last(&mut self, control: Control) -> Option<KeyBinding> {
+        self.keys.get_mut(&control).and_then(Vec::pop)
+    }
+
+    /// Controls sharing a key with another control, as can happen in a hand-edited config
+    pub fn get_conflicts(&self) -> Vec<Control> {
+        Control::ALL
+            .into_iter()
+            .filter(|&control| {
+                self.get_keys(control).iter().any(|&binding| {
+                    Control::ALL.into_iter().any(|other| {
+                        other != control
+                            && !other.can_share_keys(control)
+                            && self.get_keys(other).contains(&binding)
+                    })
+                })
+            })
+            .collect()
+    }
+
+    /// A control's keys joined for display, e.g. `W/Up`
+    pub fn get_keys_label(&self, control: Control) -> String {
+        join_keys(self.get_keys(control))
+    }
+
+    /// Like `get_keys_label`, leaving out keys the other player takes in co-op
+    pub fn get_active_keys_label(&self, control: Control, co_op: bool) -> String {
+        join_keys(&self.get_active_keys(control, co_op))
+    }
+
+    /// A player's four movement controls' keys grouped into sets, e.g. `WASD/Arrows`, leaving
+    /// out keys the other player takes in co-op
+    pub fn get_move_label(&self, player: usize, co_op: bool) -> String {
+        let directions = if player == 0 {
+            [
+                Control::MoveUp,
+                Control::MoveLeft,
+                Control::MoveDown,
+                Control::MoveRight,
+            ]
+        } else {
+            [
+                Control::P2MoveUp,
+                Control::P2MoveLeft,
+                Control::P2MoveDown,
+                Control::P2MoveRight,
+            ]
+        };
+        let keys = directions.map(|control| self.get_active_keys(control, co_op));
+        let sets = keys.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut groups = Vec::new();
+        for set in 0..sets {
+            let names: Vec<String> = keys
+                .iter()
+                .filter_map(|keys| keys.get(set))
+                .map(KeyBinding::to_string)
+                .collect();
+            let group = if names == ["Up", "Left", "Down", "Right"] {
+                "Arrows".to_string()
+            } else if names.len() == 4 && names.iter().all(|name| name.chars().count() == 1) {
+                names.concat()
+            } else {
+                names.join("/")
+            };
+            groups.push(group);
+        }
+
+        if groups.is_empty() {
+            "-".to_string()
+        } else {
+            groups.join("/")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_names_round_trip() {
+        for name in ["W", "Space", "F5", "Ctrl+Up", "LShift", "Alt+Ctrl+X", "+"] {
+            let binding: KeyBinding = name.parse().unwrap();
+            let reparsed: KeyBinding = binding.to_string().parse().unwrap();
+            assert_eq!(reparsed, binding, "{}", name);
+        }
+        assert_eq!("w".parse(), Ok(KeyBinding::key(KeyCode::Char('w'))));
+        assert_eq!("enter".parse(), Ok(KeyBinding::key(KeyCode::Enter)));
+        assert!("Hyper+W".parse::<KeyBinding>().is_err());
+        assert!("Banana".parse::<KeyBinding>().is_err());
+    }
+
+    #[test]
+    fn test_shifted_letters_match_their_binding() {
+        let bindings = KeyBindings::default();
+        let shifted = KeyEvent::new(KeyCode::Char('W'), KeyModifiers::SHIFT);
+        let binding = KeyBinding::from_event(&shifted).unwrap();
+        assert_eq!(bindings.get_control(binding, false), Some(Control::MoveUp));
+        assert_eq!(
+            bindings.get_control_for_code(KeyCode::Char('W'), false),
+            Some(Control::MoveUp)
+        );
+    }
+
+    #[test]
+    fn test_bind_refuses_keys_in_use() {
+        let mut bindings = KeyBindings::default();
+        let b = KeyBinding::key(KeyCode::Char('b'));
+        assert_eq!(bindings.bind(Control::Fire, b), Err(Control::Bomb));
+
+        let k = KeyBinding::key(KeyCode::Char('k'));
+        assert_eq!(bindings.bind(Control::Fire, k), Ok(()));
+        assert_eq!(bindings.get_keys(Control::Fire).len(), 2);
+        assert_eq!(bindings.get_control(k, false), Some(Control::Fire));
+
+        assert_eq!(bindings.unbind_last(Control::Fire), Some(k));
+        assert_eq!(bindings.get_control(k, false), None);
+    }
+
+    #[test]
+    fn test_config_table_overrides_some_controls() {
+        let keys: BTreeMap<Control, Vec<KeyBinding>> =
+            toml::from_str("fire = [\"Enter\", \"j\"]\nbomb = [\"j\"]").unwrap();
+        let bindings = KeyBindings::from(keys);
+
+        assert_eq!(bindings.get_keys_label(Control::Fire), "Enter/J");
+        // Controls left out keep their defaults
+        assert_eq!(bindings.get_keys_label(Control::Dash), "E");
+        assert_eq!(bindings.get_conflicts(), vec![Control::Fire, Control::Bomb]);
+        assert!(KeyBindings::default().get_conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_bad_config_entries_keep_defaults() {
+        let mut bindings: KeyBindings =
+            toml::from_str("fire = [\"j\"]\nbomb = [\"n\", \"Spcae\"]\nfier = [\"k\"]").unwrap();
+
+        assert_eq!(bindings.get_keys_label(Control::Fire), "J");
+        // Only the control with the mistyped key falls back to its defaults
+        assert_eq!(bindings.get_keys_label(Control::Bomb), "B");
+        assert_eq!(
+            bindings.take_load_warnings(),
+            vec![
+                "unknown key 'Spcae' for bomb in [bindings], keeping its default keys".to_string(),
+                "unknown control 'fier' in [bindings]".to_string(),
+            ]
+        );
+        assert!(bindings.take_load_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_move_label_groups_key_sets() {
+        let mut bindings = KeyBindings::default();
+        assert_eq!(bindings.get_move_label(0, false), "WASD/Arrows");
+
+        bindings.unbind_last(Control::MoveUp);
+        bindings.unbind_last(Control::MoveUp);
+        bindings
+            .bind(Control::MoveUp, KeyBinding::key(KeyCode::Char('i')))
+            .unwrap();
+        assert_eq!(bindings.get_move_label(0, false), "IASD/Left/Down/Right");
+    }
+
+    #[test]
+    fn test_co_op_gives_player_two_the_arrows() {
+        let bindings = KeyBindings::default();
+        let up = KeyBinding::key(KeyCode::Up);
+        let enter = KeyBinding::key(KeyCode::Enter);
+        assert_eq!(bindings.get_control(up, false), Some(Control::MoveUp));
+        assert_eq!(bindings.get_control(enter, false), None);
+        assert_eq!(bindings.get_control(up, true), Some(Control::P2MoveUp));
+        assert_eq!(bindings.get_control(enter, true), Some(Control::P2Fire));
+
+        assert_eq!(bindings.get_move_label(0, true), "WASD");
+        assert_eq!(bindings.get_move_label(1, true), "Arrows");
+        assert_eq!(bindings.get_keys_label(Control::P2Fire), "Enter/RCtrl");
+
+        // Right Shift is player one's focus key alone, and player two's in co-op
+        let right_shift = KeyBinding::key(KeyCode::Modifier(ModifierKeyCode::RightShift));
+        assert_eq!(
+            bindings.get_control(right_shift, false),
+            Some(Control::Focus)
+        );
+        assert_eq!(
+            bindings.get_control(right_shift, true),
+            Some(Control::P2Focus)
+        );
+        assert_eq!(
+            bindings.get_active_keys_label(Control::Focus, true),
+            "LShift"
+        );
+        assert_eq!(
+            bindings.get_active_keys_label(Control::Focus, false),
+            "LShift/RShift"
+        );
+        assert!(bindings.get_conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_player_two_only_shares_keys_with_matching_controls() {
+        let mut bindings = KeyBindings::default();
+        let i = KeyBinding::key(KeyCode::Char('i'));
+        assert_eq!(bindings.bind(Control::MoveUp, i), Ok(()));
+        assert_eq!(bindings.bind(Control::P2MoveUp, i), Ok(()));
+        assert_eq!(
+            bindings.bind(Control::P2MoveDown, i),
+            Err(Control::P2MoveUp)
+        );
+
+        let b = KeyBinding::key(KeyCode::Char('b'));
+        assert_eq!(bindings.bind(Control::P2Fire, b), Err(Control::Bomb));
+        assert_eq!(bindings.bind(Control::P2Bomb, b), Ok(()));
+        assert_eq!(bindings.bind(Control::P2Dash, b), Err(Control::Bomb));
+        assert!(bindings.get_conflicts().is_empty());
+    }
+}
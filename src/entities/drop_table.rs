@@ -0,0 +1,109 @@
+use rand::Rng;
+
+use super::enemy::Enemy;
+use super::pickup::PickupKind;
+
+/// Kills in a row without a drop before the next kill is guaranteed to drop
+pub const PITY_KILLS: u32 = 12;
+
+/// Decides which destroyed enemies drop pickups
+#[derive(Debug, Clone, Default)]
+pub struct DropTable {
+    /// Kills since the last pickup dropped
+    pub kills_since_drop: u32,
+}
+
+impl DropTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rolls for a drop from a destroyed enemy, picked from that enemy type's weights
+    /// Clearing a whole formation always drops something
+    pub fn roll(
+        &mut self,
+        enemy: &Enemy,
+        formation_cleared: bool,
+        rng: &mut impl Rng,
+    ) -> Option<PickupKind> {
+        let roll: f64 = rng.random();
+        self.should_drop(enemy.get_drop_chance(), formation_cleared, roll)
+            .then(|| PickupKind::random(enemy.get_drop_weights(), rng))
+    }
+
+    /// Decides whether a kill drops a pickup given a roll in `0.0..1.0`,
+    /// updating the pity counter
+    pub fn should_drop(&mut self, chance: f64, guaranteed: bool, roll: f64) -> bool {
+        self.kills_since_drop += 1;
+
+        let drops = guaranteed || self.kills_since_drop >= PITY_KILLS || roll < chance;
+        if drops {
+            self.kills_since_drop = 0;
+        }
+        drops
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::EnemyType;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn test_drop_when_roll_below_chance() {
+        let mut table = DropTable::new();
+        assert!(table.should_drop(0.5, false, 0.2));
+        assert!(!table.should_drop(0.5, false, 0.8));
+    }
+
+    #[test]
+    fn test_formation_clear_guarantees_drop() {
+        let mut table = DropTable::new();
+        assert!(table.should_drop(0.0, true, 0.99));
+        assert_eq!(table.kills_since_drop, 0);
+    }
+
+    #[test]
+    fn test_pity_timer_bounds_dry_streaks() {
+        let mut table = DropTable::new();
+        for _ in 0..PITY_KILLS - 1 {
+            assert!(!table.should_drop(0.0, false, 0.99));
+        }
+        assert_eq!(table.kills_since_drop, PITY_KILLS - 1);
+
+        // Next kill is guaranteed, and the counter resets
+        assert!(table.should_drop(0.0, false, 0.99));
+        assert_eq!(table.kills_since_drop, 0);
+    }
+
+    #[test]
+    fn test_each_enemy_type_drops_from_its_own_table() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut table = DropTable::new();
+        for enemy_type in [EnemyType::Basic, EnemyType::Fast, EnemyType::Tank] {
+            let enemy = Enemy::new_in_formation(10, 10, enemy_type, 0, (0, 0));
+            let weights = enemy.get_drop_weights();
+            for _ in 0..500 {
+                let kind = table.roll(&enemy, true, &mut rng).unwrap();
+                assert!(
+                    weights.iter().any(|(k, w)| *k == kind && *w > 0),
+                    "{:?} dropped {:?}",
+                    enemy_type,
+                    kind
+                );
+            }
+        }
+
+        // The tables lean different ways
+        let weight = |enemy_type, kind| {
+            Enemy::new_in_formation(0, 0, enemy_type, 0, (0, 0))
+                .get_drop_weights()
+                .iter()
+                .find(|(k, _)| *k == kind)
+                .map_or(0, |(_, w)| *w)
+        };
+        assert_eq!(weight(EnemyType::Tank, PickupKind::Medal), 0);
+        assert_eq!(weight(EnemyType::Basic, PickupKind::Shield), 0);
+    }
+}
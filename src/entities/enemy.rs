@@ -1,3 +1,5 @@
+use super::pickup::{BASIC_DROP_WEIGHTS, PICKUP_SPAWN_WEIGHTS, PickupKind, TANK_DROP_WEIGHTS};
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EnemyType {
     Basic,
@@ -5,8 +7,20 @@ pub enum EnemyType {
     Tank,
 }
 
+impl EnemyType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            EnemyType::Basic => "Basic",
+            EnemyType::Fast => "Fast",
+            EnemyType::Tank => "Tank",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Enemy {
+    /// Unique id assigned when spawned, stable while other enemies are removed
+    pub id: u32,
     pub x: u16,
     pub y: u16,
     pub health: u8,
@@ -17,6 +31,10 @@ pub struct Enemy {
     /// Offset from formation center
     pub formation_offset: (i16, i16),
     pub damage_flash_frames: u8,
+    /// Broke away from its formation and is diving at the player
+    pub diving: bool,
+    /// Id of the player who last damaged it, credited with the kill
+    pub last_hit_by: Option<usize>,
 }
 
 impl Enemy {
@@ -34,6 +52,7 @@ impl Enemy {
         };
 
         Self {
+            id: 0,
             x,
             y,
             health,
@@ -42,6 +61,8 @@ impl Enemy {
             formation_id: Some(formation_id),
             formation_offset: offset,
             damage_flash_frames: 0,
+            diving: false,
+            last_hit_by: None,
         }
     }
 
@@ -52,7 +73,7 @@ impl Enemy {
         }
 
         // Enemies in formations don't move on their own - they follow the formation
-        if self.formation_id.is_some() {
+        if self.formation_id.is_some() && !self.diving {
             self.fire_cooldown = self.fire_cooldown.wrapping_add(1);
             return;
         }
@@ -65,10 +86,15 @@ impl Enemy {
         };
 
         // Move down every few frames - slowed down significantly
-        let move_interval = match self.enemy_type {
-            EnemyType::Basic => 8, // Move every 8 frames
-            EnemyType::Fast => 5,  // Move every 5 frames (still faster)
-            EnemyType::Tank => 10, // Move every 10 frames (slowest)
+        // Divers swoop down much faster than stragglers drift
+        let move_interval = if self.diving {
+            2
+        } else {
+            match self.enemy_type {
+                EnemyType::Basic => 8, // Move every 8 frames
+                EnemyType::Fast => 5,  // Move every 5 frames (still faster)
+                EnemyType::Tank => 10, // Move every 10 frames (slowest)
+            }
         };
 
         if self.fire_cooldown.is_multiple_of(move_interval) {
@@ -91,6 +117,11 @@ impl Enemy {
         }
     }
 
+    /// Breaks away from the formation and dives straight down
+    pub fn start_dive(&mut self) {
+        self.diving = true;
+    }
+
     pub fn can_fire(&self) -> bool {
         self.fire_cooldown.is_multiple_of(120)  // Increased from 30 to 120 (2 seconds at 60 FPS)
     }
@@ -101,6 +132,12 @@ impl Enemy {
         self.damage_flash_frames = 10;
     }
 
+    /// Takes damage dealt by a player, who gets the kill if this finishes it
+    pub fn take_damage_from(&mut self, damage: u8, player: usize) {
+        self.last_hit_by = Some(player);
+        self.take_damage(damage);
+    }
+
     pub fn is_flashing(&self) -> bool {
         self.damage_flash_frames > 0
     }
@@ -133,6 +170,24 @@ impl Enemy {
         }
     }
 
+    /// Chance of dropping a pickup when destroyed
+    pub fn get_drop_chance(&self) -> f64 {
+        match self.enemy_type {
+            EnemyType::Basic => 0.05,
+            EnemyType::Fast => 0.08,
+            EnemyType::Tank => 0.25,
+        }
+    }
+
+    /// Relative chance of each pickup kind when this enemy drops one
+    pub fn get_drop_weights(&self) -> &'static [(PickupKind, u32)] {
+        match self.enemy_type {
+            EnemyType::Basic => BASIC_DROP_WEIGHTS,
+            EnemyType::Fast => PICKUP_SPAWN_WEIGHTS,
+            EnemyType::Tank => TANK_DROP_WEIGHTS,
+        }
+    }
+
     pub fn get_points(&self) -> u32 {
         match self.enemy_type {
             EnemyType::Basic => 10,
@@ -170,6 +225,13 @@ mod tests {
         assert_eq!(tank.get_points(), 30);
     }
 
+    #[test]
+    fn test_tanks_drop_more_often() {
+        let basic = Enemy::new_in_formation(10, 10, EnemyType::Basic, 0, (0, 0));
+        let tank = Enemy::new_in_formation(10, 10, EnemyType::Tank, 0, (0, 0));
+        assert!(tank.get_drop_chance() > basic.get_drop_chance());
+    }
+
     #[test]
     fn test_enemy_take_damage() {
         let mut enemy = Enemy::new_in_formation(10, 10, EnemyType::Basic, 0, (0, 0));
@@ -182,6 +244,16 @@ mod tests {
         assert!(!enemy.is_alive());
     }
 
+    #[test]
+    fn test_enemy_remembers_last_attacker() {
+        let mut enemy = Enemy::new_in_formation(10, 10, EnemyType::Basic, 0, (0, 0));
+        assert_eq!(enemy.last_hit_by, None);
+        enemy.take_damage_from(5, 0);
+        enemy.take_damage_from(10, 1);
+        assert!(!enemy.is_alive());
+        assert_eq!(enemy.last_hit_by, Some(1));
+    }
+
     #[test]
     fn test_enemy_update_formation_position() {
         let mut enemy = Enemy::new_in_formation(10, 10, EnemyType::Basic, 0, (5, 3));
@@ -198,6 +270,21 @@ mod tests {
         assert_eq!(enemy.y, 13);
     }
 
+    #[test]
+    fn test_enemy_dive_leaves_formation() {
+        let mut enemy = Enemy::new_in_formation(10, 10, EnemyType::Basic, 0, (0, 0));
+        enemy.update();
+        assert_eq!(enemy.y, 10);
+
+        enemy.start_dive();
+        for _ in 0..4 {
+            enemy.update();
+        }
+        assert!(enemy.y > 10);
+        // Still counts toward its formation for clear bonuses
+        assert_eq!(enemy.formation_id, Some(0));
+    }
+
     #[test]
     fn test_enemy_damage_flash() {
         let mut enemy = Enemy::new_in_formation(10, 10, EnemyType::Basic, 0, (0, 0));
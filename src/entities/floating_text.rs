@@ -0,0 +1,93 @@
+/// Frames a floating text stays on screen (about 2/3 of a second at 60 FPS)
+pub const FLOATING_TEXT_LIFETIME: u8 = 40;
+
+/// Frames between each one-row rise
+const RISE_INTERVAL: u8 = 8;
+
+/// What a floating text is reporting, used to pick its colour
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatingTextKind {
+    /// Points earned from a kill
+    Score,
+    /// Chain multiplier going up
+    Chain,
+    /// Formation-clear bonus
+    Bonus,
+    /// Damage dealt to an enemy
+    Damage,
+    /// Damage taken by the player
+    PlayerDamage,
+    /// Name of a collected pickup
+    Pickup,
+}
+
+/// Short-lived text that drifts upward and fades, e.g. "+30" over a kill
+#[derive(Debug, Clone)]
+pub struct FloatingText {
+    /// Column the text is centered on
+    pub x: u16,
+    pub y: u16,
+    pub text: String,
+    pub kind: FloatingTextKind,
+    pub lifetime: u8,
+}
+
+impl FloatingText {
+    pub fn new(x: u16, y: u16, text: impl Into<String>, kind: FloatingTextKind) -> Self {
+        Self {
+            x,
+            y,
+            text: text.into(),
+            kind,
+            lifetime: FLOATING_TEXT_LIFETIME,
+        }
+    }
+
+    pub fn update(&mut self) {
+        if self.lifetime > 0 {
+            self.lifetime -= 1;
+        }
+
+        // Drift upward, stopping at the top of the screen
+        if self.lifetime.is_multiple_of(RISE_INTERVAL) {
+            self.y = self.y.saturating_sub(1);
+        }
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.lifetime == 0
+    }
+
+    /// True for the last third of the text's life, when it should be drawn dimmed
+    pub fn is_fading(&self) -> bool {
+        self.lifetime < FLOATING_TEXT_LIFETIME / 3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floating_text_rises_and_dies() {
+        let mut text = FloatingText::new(10, 20, "+30", FloatingTextKind::Score);
+        assert_eq!(text.text, "+30");
+        assert!(!text.is_fading());
+
+        for _ in 0..FLOATING_TEXT_LIFETIME {
+            text.update();
+        }
+        assert!(text.is_dead());
+        assert!(text.is_fading());
+        assert_eq!(text.y, 20 - (FLOATING_TEXT_LIFETIME / RISE_INTERVAL) as u16);
+    }
+
+    #[test]
+    fn test_floating_text_stops_at_top() {
+        let mut text = FloatingText::new(10, 0, "SHIELD", FloatingTextKind::Pickup);
+        for _ in 0..FLOATING_TEXT_LIFETIME {
+            text.update();
+        }
+        assert_eq!(text.y, 0);
+    }
+}
@@ -1,6 +1,156 @@
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GameState {
+    /// Animated logo and attract demo shown at launch
+    Title,
+    MainMenu,
+    /// Full high-score table opened from the main menu
+    HighScores,
+    Settings,
+    ShipSelect,
     Playing,
+    /// Pause menu over the running game
     Paused,
+    /// List of controls opened from the pause menu
+    Controls,
+    /// Yes/no prompt before a destructive pause menu choice
+    Confirmation,
+    /// Typing initials for a score that made the high-score table
+    EnterInitials,
     GameOver,
 }
+
+impl GameState {
+    /// Screens drawn on top of the screen beneath them instead of replacing it
+    pub fn is_overlay(&self) -> bool {
+        matches!(
+            self,
+            GameState::Paused | GameState::Controls | GameState::Confirmation
+        )
+    }
+
+    /// Screens navigated with the shared menu keys (arrows, Enter, Esc to go back)
+    pub fn is_menu(&self) -> bool {
+        matches!(
+            self,
+            GameState::MainMenu
+                | GameState::HighScores
+                | GameState::Settings
+                | GameState::ShipSelect
+                | GameState::Paused
+                | GameState::Controls
+                | GameState::Confirmation
+        )
+    }
+}
+
+/// Stack of open screens; the top one receives input and overlays are drawn
+/// over the screens beneath them
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenStack {
+    screens: Vec<GameState>,
+}
+
+impl ScreenStack {
+    pub fn new(root: GameState) -> Self {
+        Self {
+            screens: vec![root],
+        }
+    }
+
+    /// The screen on top of the stack
+    pub fn current(&self) -> GameState {
+        *self.screens.last().expect("screen stack is never empty")
+    }
+
+    /// Opens a screen on top of the current one
+    pub fn push(&mut self, screen: GameState) {
+        self.screens.push(screen);
+    }
+
+    /// Closes the top screen, returning to the one beneath it
+    /// The bottom screen is never popped, returns None instead
+    pub fn pop(&mut self) -> Option<GameState> {
+        if self.screens.len() > 1 {
+            self.screens.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Swaps the top screen for another
+    pub fn replace(&mut self, screen: GameState) {
+        *self
+            .screens
+            .last_mut()
+            .expect("screen stack is never empty") = screen;
+    }
+
+    /// Drops every screen and starts over from the given stack, bottom first
+    pub fn reset(&mut self, screens: &[GameState]) {
+        assert!(!screens.is_empty(), "screen stack needs a bottom screen");
+        self.screens = screens.to_vec();
+    }
+
+    /// Screens that need drawing, bottom first: the topmost full screen and any overlays above it
+    pub fn get_visible(&self) -> &[GameState] {
+        let base = self
+            .screens
+            .iter()
+            .rposition(|screen| !screen.is_overlay())
+            .unwrap_or(0);
+        &self.screens[base..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_screen_stack_push_and_pop() {
+        let mut screens = ScreenStack::new(GameState::Title);
+        screens.push(GameState::MainMenu);
+        screens.push(GameState::Settings);
+        assert_eq!(screens.current(), GameState::Settings);
+
+        assert_eq!(screens.pop(), Some(GameState::Settings));
+        assert_eq!(screens.pop(), Some(GameState::MainMenu));
+        // The bottom screen stays put
+        assert_eq!(screens.pop(), None);
+        assert_eq!(screens.current(), GameState::Title);
+    }
+
+    #[test]
+    fn test_screen_stack_replace_and_reset() {
+        let mut screens = ScreenStack::new(GameState::Playing);
+        screens.replace(GameState::GameOver);
+        assert_eq!(screens.current(), GameState::GameOver);
+
+        screens.reset(&[GameState::Title, GameState::MainMenu]);
+        assert_eq!(screens.current(), GameState::MainMenu);
+        assert_eq!(screens.pop(), Some(GameState::MainMenu));
+    }
+
+    #[test]
+    fn test_screen_stack_overlays_show_screen_beneath() {
+        let mut screens = ScreenStack::new(GameState::Title);
+        screens.push(GameState::Playing);
+        assert_eq!(screens.get_visible(), &[GameState::Playing]);
+
+        screens.push(GameState::Paused);
+        screens.push(GameState::Confirmation);
+        assert_eq!(
+            screens.get_visible(),
+            &[
+                GameState::Playing,
+                GameState::Paused,
+                GameState::Confirmation
+            ]
+        );
+
+        // A full screen opened from an overlay hides everything beneath it
+        screens.pop();
+        screens.push(GameState::Settings);
+        assert_eq!(screens.get_visible(), &[GameState::Settings]);
+    }
+}
@@ -0,0 +1,85 @@
+use super::enemy::Enemy;
+
+/// Heat at which the laser overheats and locks out until fully cooled
+pub const LASER_MAX_HEAT: u8 = 120;
+
+/// Heat shed per frame while the laser is not firing
+pub const LASER_COOL_RATE: u8 = 2;
+
+/// A vertical laser beam cast from the ship for a single frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LaserBeam {
+    /// Column the beam travels along
+    pub x: u16,
+    /// Row where the beam stops (enemy it hit or top of the screen)
+    pub top_y: u16,
+    /// Row the beam starts from, just above the ship
+    pub bottom_y: u16,
+    /// Index of the enemy the beam hit, if any
+    pub hit_enemy: Option<usize>,
+}
+
+impl LaserBeam {
+    /// Casts a beam straight up from (x, from_y), stopping at the closest
+    /// living enemy that covers the column
+    pub fn cast(x: u16, from_y: u16, enemies: &[Enemy]) -> Self {
+        let hit = enemies
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.is_alive() && x >= e.x && x < e.x + e.get_width() && e.y <= from_y)
+            .max_by_key(|(_, e)| e.y + e.get_height());
+
+        match hit {
+            Some((idx, enemy)) => Self {
+                x,
+                // Stop at the enemy's bottom edge, or at the ship if the enemy overlaps it
+                top_y: (enemy.y + enemy.get_height() - 1).min(from_y),
+                bottom_y: from_y,
+                hit_enemy: Some(idx),
+            },
+            None => Self {
+                x,
+                top_y: 0,
+                bottom_y: from_y,
+                hit_enemy: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::EnemyType;
+
+    #[test]
+    fn test_beam_reaches_top_without_enemies() {
+        let beam = LaserBeam::cast(10, 20, &[]);
+        assert_eq!(beam.top_y, 0);
+        assert_eq!(beam.bottom_y, 20);
+        assert_eq!(beam.hit_enemy, None);
+    }
+
+    #[test]
+    fn test_beam_stops_at_closest_enemy() {
+        let enemies = vec![
+            Enemy::new_in_formation(8, 2, EnemyType::Basic, 0, (0, 0)),
+            Enemy::new_in_formation(8, 10, EnemyType::Basic, 0, (0, 0)),
+            // Not in the beam's column
+            Enemy::new_in_formation(30, 15, EnemyType::Basic, 0, (0, 0)),
+        ];
+        let beam = LaserBeam::cast(10, 20, &enemies);
+        assert_eq!(beam.hit_enemy, Some(1));
+        assert_eq!(beam.top_y, 12);
+    }
+
+    #[test]
+    fn test_beam_ignores_dead_and_lower_enemies() {
+        let mut dead = Enemy::new_in_formation(8, 10, EnemyType::Basic, 0, (0, 0));
+        dead.health = 0;
+        let below = Enemy::new_in_formation(8, 25, EnemyType::Basic, 0, (0, 0));
+        let beam = LaserBeam::cast(10, 20, &[dead, below]);
+        assert_eq!(beam.hit_enemy, None);
+        assert_eq!(beam.top_y, 0);
+    }
+}
@@ -1,16 +1,32 @@
+mod drop_table;
 mod enemy;
+mod floating_text;
 mod formation;
 mod game_state;
+mod laser;
+mod movement;
 mod particle;
 mod pickup;
 mod player;
 mod projectile;
+mod score_chain;
+mod ship;
 
 // Re-export all public types
+pub use drop_table::DropTable;
 pub use enemy::{Enemy, EnemyType};
+pub use floating_text::{FloatingText, FloatingTextKind};
 pub use formation::{Formation, FormationType};
-pub use game_state::GameState;
-pub use particle::{Particle, create_explosion_particles};
-pub use pickup::Pickup;
-pub use player::{Player, WeaponType};
-pub use projectile::{Projectile, ProjectileOwner, ProjectileType};
+pub use game_state::{GameState, ScreenStack};
+pub use laser::{LASER_MAX_HEAT, LaserBeam};
+pub use movement::{MovementBounds, MovementModel, SUBCELLS_PER_CELL};
+pub use particle::{Particle, create_explosion_particles, create_shockwave_particles};
+pub use pickup::{Pickup, PickupKind};
+pub use player::{
+    DashState, Player, SHIELD_DURATION_FRAMES, SHIELD_HITS, SPEED_BOOST_FRAMES, WeaponType,
+};
+pub use projectile::{
+    Projectile, ProjectileInteraction, ProjectileOwner, ProjectileType, SHOOTABLE_ENEMY_PROJECTILES,
+};
+pub use score_chain::{CHAIN_WINDOW_FRAMES, ScoreChain};
+pub use ship::{SHIPS, ShipPassive};
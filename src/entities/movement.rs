@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+
+/// Movement is tracked in sub-cells so the ship can travel less than a cell per frame
+pub const SUBCELLS_PER_CELL: u16 = 8;
+
+/// How held movement keys translate into ship motion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MovementModel {
+    /// Instant start and stop at full speed (the classic feel)
+    #[default]
+    Arcade,
+    /// The ship accelerates up to max speed and drifts to a stop under drag
+    Inertial,
+}
+
+impl MovementModel {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            MovementModel::Arcade => "Arcade",
+            MovementModel::Inertial => "Inertial",
+        }
+    }
+
+    /// The other handling model, for toggling at runtime
+    pub fn toggled(&self) -> Self {
+        match self {
+            MovementModel::Arcade => MovementModel::Inertial,
+            MovementModel::Inertial => MovementModel::Arcade,
+        }
+    }
+}
+
+/// Per-ship handling, all in sub-cells per frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Handling {
+    /// Top speed with no speed boost
+    pub max_speed: i16,
+    /// Velocity gained per frame while a direction is held (inertial only)
+    pub acceleration: i16,
+    /// Velocity lost per frame once the direction is released (inertial only)
+    pub drag: i16,
+}
+
+impl Default for Handling {
+    fn default() -> Self {
+        Self {
+            max_speed: SUBCELLS_PER_CELL as i16,
+            acceleration: 2,
+            drag: 1,
+        }
+    }
+}
+
+/// Area the ship's top-left corner may move within, in game-area coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MovementBounds {
+    pub min_x: u16,
+    pub max_x: u16,
+    pub min_y: u16,
+    pub max_y: u16,
+}
+
+impl MovementBounds {
+    /// Bounds for a ship of the given size inside the bordered game area
+    pub fn for_game_area(
+        screen_width: u16,
+        screen_height: u16,
+        edge_width: u16,
+        ship_width: u16,
+        ship_height: u16,
+    ) -> Self {
+        // Player coordinates are relative to game area, so min is 0
+        let min_x = 0;
+        // Max x is based on playable game area width
+        // Game area width = screen_width - (edge_width * 2) - 2 (for borders)
+        // The player occupies positions [x, x+width), so max valid x is width - player_width
+        let game_area_width = screen_width.saturating_sub(edge_width * 2 + 2);
+        // Use saturating_sub to prevent underflow, then subtract 1 more for safety
+        let max_x = game_area_width.saturating_sub(ship_width + 1);
+        let min_y = 2; // Leave space for HUD
+        let max_y = screen_height.saturating_sub(ship_height + 1);
+
+        Self {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
+}
+
+/// Velocity for a held direction at the given speed, scaled by ~1/sqrt(2)
+/// on diagonals so moving diagonally isn't faster than moving straight
+pub fn scaled_direction(dx: i16, dy: i16, speed: i16) -> (i16, i16) {
+    let dx = dx.signum();
+    let dy = dy.signum();
+    if dx != 0 && dy != 0 {
+        // 181/256 ~= 0.707, rounded to the nearest sub-cell
+        let diagonal = (speed * 181 + 128) / 256;
+        (dx * diagonal, dy * diagonal)
+    } else {
+        (dx * speed, dy * speed)
+    }
+}
+
+/// Moves a velocity toward its target by acceleration while steering, or by drag while coasting
+pub fn approach(velocity: i16, target: i16, steering: bool, handling: &Handling) -> i16 {
+    let rate = if steering {
+        handling.acceleration
+    } else {
+        handling.drag
+    };
+
+    if velocity < target {
+        (velocity + rate).min(target)
+    } else {
+        (velocity - rate).max(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_for_game_area() {
+        let bounds = MovementBounds::for_game_area(100, 40, 10, 5, 3);
+        assert_eq!(bounds.min_x, 0);
+        assert_eq!(bounds.max_x, 100 - 22 - 6);
+        assert_eq!(bounds.min_y, 2);
+        assert_eq!(bounds.max_y, 36);
+    }
+
+    #[test]
+    fn test_bounds_tiny_screen_does_not_underflow() {
+        let bounds = MovementBounds::for_game_area(10, 2, 10, 5, 3);
+        assert_eq!(bounds.max_x, 0);
+        assert_eq!(bounds.max_y, 0);
+    }
+
+    #[test]
+    fn test_scaled_direction_normalizes_diagonals() {
+        assert_eq!(scaled_direction(1, 0, 8), (8, 0));
+        assert_eq!(scaled_direction(0, -1, 8), (0, -8));
+        assert_eq!(scaled_direction(-1, 1, 8), (-6, 6));
+        assert_eq!(scaled_direction(0, 0, 8), (0, 0));
+    }
+
+    #[test]
+    fn test_approach_uses_acceleration_then_drag() {
+        let handling = Handling::default();
+        assert_eq!(approach(0, 8, true, &handling), 2);
+        assert_eq!(approach(7, 8, true, &handling), 8);
+        assert_eq!(approach(8, 0, false, &handling), 7);
+        assert_eq!(approach(-1, 0, false, &handling), 0);
+    }
+
+    #[test]
+    fn test_movement_model_toggles() {
+        assert_eq!(MovementModel::default(), MovementModel::Arcade);
+        assert_eq!(MovementModel::Arcade.toggled(), MovementModel::Inertial);
+        assert_eq!(MovementModel::Inertial.toggled(), MovementModel::Arcade);
+    }
+}
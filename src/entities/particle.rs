@@ -87,6 +87,24 @@ pub fn create_explosion_particles(center_x: u16, center_y: u16) -> Vec<Particle>
     particles
 }
 
+/// Creates an expanding ring of particles, used for smart-bomb shockwaves
+pub fn create_shockwave_particles(center_x: u16, center_y: u16) -> Vec<Particle> {
+    let mut particles = Vec::new();
+
+    // Ring of particles flying outward; horizontal speed doubled for terminal cell aspect
+    for i in 0..24 {
+        let angle = i as f32 * std::f32::consts::TAU / 24.0;
+        let velocity_x = (angle.cos() * 4.0).round() as i16;
+        let velocity_y = (angle.sin() * 2.0).round() as i16;
+        particles.push(Particle::new(
+            center_x, center_y, velocity_x, velocity_y, 12, // Lasts 12 frames (~0.2 seconds)
+            '#',
+        ));
+    }
+
+    particles
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,6 +148,16 @@ mod tests {
         assert!(!particle.is_out_of_bounds(0, 80, 24));
     }
 
+    #[test]
+    fn test_create_shockwave_particles() {
+        let particles = create_shockwave_particles(40, 20);
+        assert_eq!(particles.len(), 24);
+        // Every particle moves outward
+        for particle in particles.iter() {
+            assert!(particle.velocity_x != 0 || particle.velocity_y != 0);
+        }
+    }
+
     #[test]
     fn test_create_explosion_particles() {
         let particles = create_explosion_particles(10, 10);
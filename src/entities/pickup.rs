@@ -1,19 +1,103 @@
+use rand::Rng;
+
 use super::player::WeaponType;
 
+/// What a pickup gives the player when collected
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PickupKind {
+    Weapon(WeaponType),
+    /// Restores health
+    Repair,
+    /// Temporary shield that absorbs hits
+    Shield,
+    /// Extra smart-bomb charge
+    Bomb,
+    /// Bonus score
+    Medal,
+    /// Temporary movement speed boost
+    SpeedBoost,
+}
+
+/// Relative chance of each pickup kind being spawned: an even mix of every kind, dropped
+/// by Fast enemies
+pub const PICKUP_SPAWN_WEIGHTS: &[(PickupKind, u32)] = &[
+    (PickupKind::Weapon(WeaponType::BasicGun), 3),
+    (PickupKind::Weapon(WeaponType::Sword), 3),
+    (PickupKind::Weapon(WeaponType::Bug), 3),
+    (PickupKind::Weapon(WeaponType::Bomber), 3),
+    (PickupKind::Weapon(WeaponType::Homing), 3),
+    (PickupKind::Weapon(WeaponType::Laser), 3),
+    (PickupKind::Repair, 3),
+    (PickupKind::Shield, 2),
+    (PickupKind::Bomb, 2),
+    (PickupKind::Medal, 4),
+    (PickupKind::SpeedBoost, 2),
+];
+
+/// Basic enemies mostly drop medals, with the odd repair or starter weapon
+pub const BASIC_DROP_WEIGHTS: &[(PickupKind, u32)] = &[
+    (PickupKind::Weapon(WeaponType::BasicGun), 1),
+    (PickupKind::Weapon(WeaponType::Bug), 1),
+    (PickupKind::Repair, 2),
+    (PickupKind::Bomb, 1),
+    (PickupKind::Medal, 8),
+    (PickupKind::SpeedBoost, 2),
+];
+
+/// Tanks drop weapons and defences rather than medals
+pub const TANK_DROP_WEIGHTS: &[(PickupKind, u32)] = &[
+    (PickupKind::Weapon(WeaponType::BasicGun), 2),
+    (PickupKind::Weapon(WeaponType::Sword), 3),
+    (PickupKind::Weapon(WeaponType::Bug), 2),
+    (PickupKind::Weapon(WeaponType::Bomber), 3),
+    (PickupKind::Weapon(WeaponType::Homing), 3),
+    (PickupKind::Weapon(WeaponType::Laser), 3),
+    (PickupKind::Repair, 2),
+    (PickupKind::Shield, 5),
+    (PickupKind::Bomb, 2),
+];
+
+impl PickupKind {
+    /// Picks a random kind from a table of relative weights
+    pub fn random(weights: &[(PickupKind, u32)], rng: &mut impl Rng) -> Self {
+        let total: u32 = weights.iter().map(|(_, w)| w).sum();
+        let mut roll = rng.random_range(0..total);
+        for (kind, weight) in weights {
+            if roll < *weight {
+                return *kind;
+            }
+            roll -= weight;
+        }
+        PickupKind::Medal
+    }
+
+    /// Short label shown when the pickup is collected
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            PickupKind::Weapon(weapon_type) => weapon_type.get_name(),
+            PickupKind::Repair => "REPAIR",
+            PickupKind::Shield => "SHIELD",
+            PickupKind::Bomb => "BOMB",
+            PickupKind::Medal => "MEDAL",
+            PickupKind::SpeedBoost => "SPEED UP",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Pickup {
     pub x: u16,
     pub y: u16,
-    pub weapon_type: WeaponType,
+    pub kind: PickupKind,
     pub frame_counter: u8,
 }
 
 impl Pickup {
-    pub fn new(x: u16, y: u16, weapon_type: WeaponType) -> Self {
+    pub fn new(x: u16, y: u16, kind: PickupKind) -> Self {
         Self {
             x,
             y,
-            weapon_type,
+            kind,
             frame_counter: 0,
         }
     }
@@ -39,11 +123,71 @@ impl Pickup {
     }
 
     pub fn get_char(&self) -> char {
-        match self.weapon_type {
-            WeaponType::BasicGun => 'G',
-            WeaponType::Sword => 'S',
-            WeaponType::Bug => 'B',
-            WeaponType::Bomber => 'X',
+        match self.kind {
+            PickupKind::Weapon(WeaponType::BasicGun) => 'G',
+            PickupKind::Weapon(WeaponType::Sword) => 'S',
+            PickupKind::Weapon(WeaponType::Bug) => 'B',
+            PickupKind::Weapon(WeaponType::Bomber) => 'X',
+            PickupKind::Weapon(WeaponType::Homing) => 'H',
+            PickupKind::Weapon(WeaponType::Laser) => 'L',
+            PickupKind::Repair => '+',
+            PickupKind::Shield => '@',
+            PickupKind::Bomb => '%',
+            PickupKind::Medal => '$',
+            PickupKind::SpeedBoost => '»',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn test_pickup_glyphs_are_unique() {
+        let mut glyphs: Vec<char> = PICKUP_SPAWN_WEIGHTS
+            .iter()
+            .map(|(kind, _)| Pickup::new(0, 0, *kind).get_char())
+            .collect();
+        let count = glyphs.len();
+        glyphs.sort_unstable();
+        glyphs.dedup();
+        assert_eq!(glyphs.len(), count);
+    }
+
+    #[test]
+    fn test_pickup_falls_slowly() {
+        let mut pickup = Pickup::new(5, 3, PickupKind::Medal);
+        for _ in 0..14 {
+            pickup.update();
+        }
+        assert_eq!(pickup.y, 3);
+        pickup.update();
+        assert_eq!(pickup.y, 4);
+    }
+
+    #[test]
+    fn test_random_kind_respects_weights() {
+        const SAMPLES: usize = 5000;
+        let mut rng = StdRng::seed_from_u64(7);
+        let kinds: Vec<PickupKind> = (0..SAMPLES)
+            .map(|_| PickupKind::random(PICKUP_SPAWN_WEIGHTS, &mut rng))
+            .collect();
+
+        // Each kind turns up about as often as its share of the total weight
+        let total: u32 = PICKUP_SPAWN_WEIGHTS.iter().map(|(_, w)| w).sum();
+        for (kind, weight) in PICKUP_SPAWN_WEIGHTS {
+            let share = *weight as f64 / total as f64;
+            let count = kinds.iter().filter(|k| *k == kind).count();
+            let frequency = count as f64 / SAMPLES as f64;
+            assert!(
+                (frequency - share).abs() < 0.02,
+                "{:?} rolled {:.3} of the time, expected {:.3}",
+                kind,
+                frequency,
+                share
+            );
         }
     }
 }
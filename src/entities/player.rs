@@ -1,4 +1,47 @@
-use super::projectile::{Projectile, ProjectileOwner, ProjectileType};
+use super::laser::{LASER_COOL_RATE, LASER_MAX_HEAT};
+use super::movement::{self, Handling, MovementBounds, MovementModel, SUBCELLS_PER_CELL};
+use super::projectile::{BOMB_RADIUS, Projectile, ProjectileOwner, ProjectileType, SLASH_RADIUS};
+use super::score_chain::ScoreChain;
+use super::ship::{SHIPS, ShipDefinition, ShipPassive};
+
+/// Highest level a weapon can be upgraded to
+pub const MAX_WEAPON_LEVEL: u8 = 5;
+
+/// Most smart-bomb charges the player can carry
+pub const MAX_BOMBS: u8 = 5;
+
+/// Smart-bomb charges the player starts a run with
+pub const STARTING_BOMBS: u8 = 2;
+
+/// Invulnerability granted by detonating a smart bomb (1 second at 60 FPS)
+pub const BOMB_INVULNERABILITY_FRAMES: u16 = 60;
+
+/// Focus-mode speed in sub-cells per frame (one cell every four frames)
+pub const FOCUS_SPEED_SUBCELLS: i16 = 2;
+
+/// Frames a dash lasts
+pub const DASH_FRAMES: u8 = 4;
+
+/// Cells moved per frame while dashing
+pub const DASH_SPEED: u16 = 3;
+
+/// Frames before another dash is allowed (1.5 seconds at 60 FPS)
+pub const DASH_COOLDOWN_FRAMES: u16 = 90;
+
+/// Extra invulnerability after a dash ends, so landing next to a bullet is forgiving
+pub const DASH_GRACE_FRAMES: u16 = 6;
+
+/// Frames a shield pickup protects the ship (5 seconds at 60 FPS)
+pub const SHIELD_DURATION_FRAMES: u16 = 300;
+
+/// Hits a shield pickup absorbs before breaking
+pub const SHIELD_HITS: u8 = 3;
+
+/// Shield starts flashing when this many frames remain (1.5 seconds at 60 FPS)
+pub const SHIELD_WARNING_FRAMES: u16 = 90;
+
+/// Frames a speed boost pickup lasts (5 seconds at 60 FPS)
+pub const SPEED_BOOST_FRAMES: u16 = 300;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum WeaponType {
@@ -6,6 +49,8 @@ pub enum WeaponType {
     Sword,
     Bug,
     Bomber,
+    Homing,
+    Laser,
 }
 
 impl WeaponType {
@@ -15,54 +60,273 @@ impl WeaponType {
             WeaponType::Sword => "Sword",
             WeaponType::Bug => "Bug",
             WeaponType::Bomber => "The Bomber",
+            WeaponType::Homing => "Homing Missile",
+            WeaponType::Laser => "Laser",
         }
     }
 }
 
+/// Dash state machine: Ready -> Dashing -> Cooldown -> Ready
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DashState {
+    Ready,
+    Dashing { frames_left: u8, dx: i16, dy: i16 },
+    Cooldown { frames_left: u16 },
+}
+
 #[derive(Debug, Clone)]
 pub struct Player {
+    /// Which player flies the ship: 0 for player one, 1 for player two in co-op
+    pub id: usize,
     pub x: u16,
     pub y: u16,
+    /// Index of the hull being flown in SHIPS
+    pub ship_index: usize,
     pub health: u8,
+    pub max_health: u8,
     pub fire_cooldown: u8,
     pub current_weapon: WeaponType,
+    /// Upgrade level of the current weapon (1..=MAX_WEAPON_LEVEL)
+    pub weapon_level: u8,
     pub damage_flash_frames: u8,
+    /// Laser heat, builds while firing and cools while idle
+    pub laser_heat: u8,
+    /// Set when the laser hit max heat; cleared once fully cooled
+    pub laser_overheated: bool,
+    /// Whether the laser fired during the current frame
+    laser_firing: bool,
+    /// Whether the laser fired during the previous frame, so holding it is one burst
+    laser_burst: bool,
+    /// Whether the current laser burst has struck an enemy yet
+    laser_burst_landed: bool,
+    /// Remaining frames of shield protection
+    pub shield_frames: u16,
+    /// Hits the shield can still absorb
+    pub shield_hits: u8,
+    /// Smart-bomb charges in stock
+    pub bombs: u8,
+    /// Remaining frames of doubled movement speed
+    pub speed_boost_frames: u16,
+    /// Remaining frames of invulnerability (e.g. after a smart bomb)
+    pub invulnerable_frames: u16,
+    pub dash_state: DashState,
+    /// Focus mode: slow, precise movement and a tighter fire pattern
+    pub focused: bool,
+    /// Direction held this frame (-1, 0 or 1 on each axis), used by steering and dashing
+    pub steer_input: (i16, i16),
+    /// Points earned by this player's kills and pickups
+    pub score: u32,
+    /// Kill chain building this player's score multiplier
+    pub score_chain: ScoreChain,
+    /// Current velocity in sub-cells per frame
+    pub velocity_x: i16,
+    pub velocity_y: i16,
+    pub handling: Handling,
+    // Leftover sub-cell movement on each axis
+    sub_x: i16,
+    sub_y: i16,
 }
 
 impl Player {
     pub fn new(x: u16, y: u16) -> Self {
+        Self::new_with_ship(x, y, 0)
+    }
+
+    /// Creates the player flying the hull at `ship_index` in SHIPS
+    pub fn new_with_ship(x: u16, y: u16, ship_index: usize) -> Self {
+        let ship = &SHIPS[ship_index];
+        let bombs = if ship.passive == ShipPassive::BombBay {
+            STARTING_BOMBS + 1
+        } else {
+            STARTING_BOMBS
+        };
+
         Self {
+            id: 0,
             x,
             y,
-            health: 100,
+            ship_index,
+            health: ship.max_health,
+            max_health: ship.max_health,
             fire_cooldown: 0,
-            current_weapon: WeaponType::BasicGun,
+            current_weapon: ship.starting_weapon,
+            weapon_level: 1,
             damage_flash_frames: 0,
+            laser_heat: 0,
+            laser_overheated: false,
+            laser_firing: false,
+            laser_burst: false,
+            laser_burst_landed: false,
+            shield_frames: 0,
+            shield_hits: 0,
+            bombs,
+            speed_boost_frames: 0,
+            invulnerable_frames: 0,
+            dash_state: DashState::Ready,
+            focused: false,
+            steer_input: (0, 0),
+            score: 0,
+            score_chain: ScoreChain::new(),
+            velocity_x: 0,
+            velocity_y: 0,
+            handling: ship.handling,
+            sub_x: 0,
+            sub_y: 0,
+        }
+    }
+
+    /// Cells moved per step, doubled while a speed boost is active
+    pub fn get_move_step(&self) -> u16 {
+        if self.speed_boost_frames > 0 { 2 } else { 1 }
+    }
+
+    /// Top movement speed in sub-cells per frame
+    pub fn get_move_speed(&self) -> i16 {
+        if self.focused {
+            FOCUS_SPEED_SUBCELLS
+        } else {
+            self.handling.max_speed * self.get_move_step() as i16
+        }
+    }
+
+    /// Adds a frame of movement to an axis accumulator, returning the whole cells to move
+    fn advance(accumulator: &mut i16, velocity: i16) -> i16 {
+        *accumulator += velocity;
+        let cells = *accumulator / SUBCELLS_PER_CELL as i16;
+        *accumulator -= cells * SUBCELLS_PER_CELL as i16;
+        cells
+    }
+
+    /// Moves the ship for one frame toward the held direction (-1, 0 or 1 on each axis)
+    pub fn steer(&mut self, dx: i16, dy: i16, model: MovementModel, bounds: &MovementBounds) {
+        let (target_x, target_y) = movement::scaled_direction(dx, dy, self.get_move_speed());
+
+        match model {
+            MovementModel::Arcade => {
+                self.velocity_x = target_x;
+                self.velocity_y = target_y;
+            }
+            MovementModel::Inertial => {
+                self.velocity_x =
+                    movement::approach(self.velocity_x, target_x, dx != 0, &self.handling);
+                self.velocity_y =
+                    movement::approach(self.velocity_y, target_y, dy != 0, &self.handling);
+            }
+        }
+
+        let cells_x = Self::advance(&mut self.sub_x, self.velocity_x);
+        if cells_x < 0 {
+            self.shift_left(cells_x.unsigned_abs(), bounds.min_x);
+        } else {
+            self.shift_right(cells_x as u16, bounds.max_x);
+        }
+
+        let cells_y = Self::advance(&mut self.sub_y, self.velocity_y);
+        if cells_y < 0 {
+            self.shift_up(cells_y.unsigned_abs(), bounds.min_y);
+        } else {
+            self.shift_down(cells_y as u16, bounds.max_y);
+        }
+
+        // Bumping into an edge kills momentum on that axis
+        if (self.x <= bounds.min_x && self.velocity_x < 0)
+            || (self.x >= bounds.max_x && self.velocity_x > 0)
+        {
+            self.velocity_x = 0;
+            self.sub_x = 0;
+        }
+        if (self.y <= bounds.min_y && self.velocity_y < 0)
+            || (self.y >= bounds.max_y && self.velocity_y > 0)
+        {
+            self.velocity_y = 0;
+            self.sub_y = 0;
         }
     }
 
-    pub fn move_left(&mut self, min_x: u16) {
+    fn shift_left(&mut self, cells: u16, min_x: u16) {
         if self.x > min_x {
-            self.x -= 1;
+            self.x = self.x.saturating_sub(cells).max(min_x);
         }
     }
 
-    pub fn move_right(&mut self, max_x: u16) {
+    fn shift_right(&mut self, cells: u16, max_x: u16) {
         if self.x < max_x {
-            self.x += 1;
+            self.x = (self.x + cells).min(max_x);
         }
     }
 
-    pub fn move_up(&mut self, min_y: u16) {
+    fn shift_up(&mut self, cells: u16, min_y: u16) {
         if self.y > min_y {
-            self.y -= 1;
+            self.y = self.y.saturating_sub(cells).max(min_y);
         }
     }
 
-    pub fn move_down(&mut self, max_y: u16) {
+    fn shift_down(&mut self, cells: u16, max_y: u16) {
         if self.y < max_y {
-            self.y += 1;
+            self.y = (self.y + cells).min(max_y);
+        }
+    }
+
+    /// Starts a dash in the given direction (-1, 0 or 1 on each axis)
+    /// Returns false if a dash is already running or cooling down
+    pub fn try_dash(&mut self, dx: i16, dy: i16) -> bool {
+        if self.dash_state != DashState::Ready || (dx == 0 && dy == 0) {
+            return false;
         }
+
+        self.dash_state = DashState::Dashing {
+            frames_left: DASH_FRAMES,
+            dx: dx.signum(),
+            dy: dy.signum(),
+        };
+        self.invulnerable_frames = self
+            .invulnerable_frames
+            .max(DASH_FRAMES as u16 + DASH_GRACE_FRAMES);
+        true
+    }
+
+    /// Advances the dash state machine, moving the ship while dashing
+    pub fn update_dash(&mut self, bounds: &MovementBounds) {
+        self.dash_state = match self.dash_state {
+            DashState::Ready => DashState::Ready,
+            DashState::Dashing {
+                frames_left,
+                dx,
+                dy,
+            } => {
+                // Dashes always cover full cells, even in focus mode
+                match dx {
+                    -1 => self.shift_left(DASH_SPEED, bounds.min_x),
+                    1 => self.shift_right(DASH_SPEED, bounds.max_x),
+                    _ => {}
+                }
+                match dy {
+                    -1 => self.shift_up(DASH_SPEED, bounds.min_y),
+                    1 => self.shift_down(DASH_SPEED, bounds.max_y),
+                    _ => {}
+                }
+
+                if frames_left > 1 {
+                    DashState::Dashing {
+                        frames_left: frames_left - 1,
+                        dx,
+                        dy,
+                    }
+                } else {
+                    DashState::Cooldown {
+                        frames_left: DASH_COOLDOWN_FRAMES,
+                    }
+                }
+            }
+            DashState::Cooldown { frames_left } if frames_left > 1 => DashState::Cooldown {
+                frames_left: frames_left - 1,
+            },
+            DashState::Cooldown { .. } => DashState::Ready,
+        };
+    }
+
+    pub fn is_dashing(&self) -> bool {
+        matches!(self.dash_state, DashState::Dashing { .. })
     }
 
     pub fn can_fire(&self) -> bool {
@@ -76,7 +340,12 @@ impl Player {
             WeaponType::Sword => 8,
             WeaponType::Bug => 10,
             WeaponType::Bomber => 30, // Much slower fire rate for bomber (0.5 seconds)
+            WeaponType::Homing => 20,
+            WeaponType::Laser => 0, // Continuous beam, limited by heat instead
         };
+        if self.get_ship().passive == ShipPassive::RapidFire {
+            self.fire_cooldown = self.fire_cooldown * 2 / 3;
+        }
     }
 
     pub fn update_cooldown(&mut self) {
@@ -86,12 +355,97 @@ impl Player {
         if self.damage_flash_frames > 0 {
             self.damage_flash_frames -= 1;
         }
+
+        // Laser cools down on frames it wasn't fired
+        if !self.laser_firing {
+            self.laser_heat = self.laser_heat.saturating_sub(LASER_COOL_RATE);
+            if self.laser_heat == 0 {
+                self.laser_overheated = false;
+            }
+        }
+        self.laser_burst = self.laser_firing;
+        self.laser_firing = false;
+
+        self.shield_frames = self.shield_frames.saturating_sub(1);
+        if self.shield_frames == 0 {
+            self.shield_hits = 0;
+        }
+        self.speed_boost_frames = self.speed_boost_frames.saturating_sub(1);
+        self.invulnerable_frames = self.invulnerable_frames.saturating_sub(1);
     }
 
     pub fn take_damage(&mut self, damage: u8) {
+        if self.is_invulnerable() {
+            return;
+        }
+
+        // Shield absorbs the hit, breaking once it runs out of hits
+        if self.is_shielded() {
+            self.shield_hits -= 1;
+            if self.shield_hits == 0 {
+                self.shield_frames = 0;
+            }
+            return;
+        }
+
+        let damage = if self.get_ship().passive == ShipPassive::Armored {
+            (damage / 2).max(1)
+        } else {
+            damage
+        };
         self.health = self.health.saturating_sub(damage);
         // Set flash timer to 10 frames (about 1/6 second at 60 FPS)
         self.damage_flash_frames = 10;
+        // Getting hit knocks the weapon down a level
+        self.weapon_level = self.weapon_level.saturating_sub(1).max(1);
+    }
+
+    /// Restores health, capped at the ship's maximum
+    pub fn heal(&mut self, amount: u8) {
+        self.health = self.health.saturating_add(amount).min(self.max_health);
+    }
+
+    /// Raises a shield lasting `frames` or until it has absorbed `hits` hits
+    pub fn activate_shield(&mut self, frames: u16, hits: u8) {
+        self.shield_frames = self.shield_frames.max(frames);
+        self.shield_hits = self.shield_hits.max(hits);
+    }
+
+    pub fn is_shielded(&self) -> bool {
+        self.shield_frames > 0 && self.shield_hits > 0
+    }
+
+    /// Whether the shield is about to run out (time nearly up or one hit left)
+    pub fn is_shield_expiring(&self) -> bool {
+        self.is_shielded() && (self.shield_frames <= SHIELD_WARNING_FRAMES || self.shield_hits == 1)
+    }
+
+    /// Adds a smart-bomb charge, returns false if already at capacity
+    pub fn add_bomb(&mut self) -> bool {
+        if self.bombs >= self.get_max_bombs() {
+            return false;
+        }
+        self.bombs += 1;
+        true
+    }
+
+    /// Spends a smart-bomb charge, granting brief invulnerability
+    /// Returns false if there are no charges left
+    pub fn use_bomb(&mut self) -> bool {
+        if self.bombs == 0 {
+            return false;
+        }
+        self.bombs -= 1;
+        self.invulnerable_frames = self.invulnerable_frames.max(BOMB_INVULNERABILITY_FRAMES);
+        true
+    }
+
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerable_frames > 0
+    }
+
+    pub fn activate_speed_boost(&mut self, frames: u16) {
+        self.speed_boost_frames = self.speed_boost_frames.max(frames);
     }
 
     pub fn is_flashing(&self) -> bool {
@@ -102,16 +456,34 @@ impl Player {
         self.health > 0
     }
 
+    pub fn get_ship(&self) -> &'static ShipDefinition {
+        &SHIPS[self.ship_index]
+    }
+
     pub fn get_sprite_lines(&self) -> Vec<&'static str> {
-        vec![" /^\\ ", "<|||>", " ||| "]
+        self.get_ship().ascii_sprite.to_vec()
     }
 
     pub fn get_width(&self) -> u16 {
-        15
+        self.get_ship().width
     }
 
     pub fn get_height(&self) -> u16 {
-        10
+        self.get_ship().height
+    }
+
+    /// Most smart-bomb charges this ship can carry
+    pub fn get_max_bombs(&self) -> u8 {
+        if self.get_ship().passive == ShipPassive::BombBay {
+            MAX_BOMBS + 2
+        } else {
+            MAX_BOMBS
+        }
+    }
+
+    /// Point above the ship that sword slashes are anchored to
+    pub fn get_slash_anchor(&self) -> (u16, u16) {
+        (self.x + self.get_width() / 2, self.y.saturating_sub(1))
     }
 
     /// Attempts to fire projectile(s) if cooldown allows
@@ -125,75 +497,170 @@ impl Player {
         let center_x = self.x + self.get_width() / 2;
         let fire_y = self.y;
 
+        let level = self.weapon_level;
+
         match self.current_weapon {
             WeaponType::BasicGun => {
-                // Single straight shot
-                vec![Projectile::new_with_type(
-                    center_x,
-                    fire_y,
-                    ProjectileOwner::Player,
-                    ProjectileType::Bullet,
-                    0,
-                    None,
-                )]
+                // One straight stream per level, spaced two cells apart (one when focused)
+                let spacing = if self.focused { 1 } else { 2 };
+                (0..level)
+                    .map(|i| {
+                        let offset = i as i16 * spacing - (level as i16 - 1) * spacing / 2;
+                        Projectile::new_with_type(
+                            (center_x as i16 + offset).max(0) as u16,
+                            fire_y,
+                            ProjectileOwner::Player(self.id),
+                            ProjectileType::Bullet,
+                            0,
+                            None,
+                        )
+                    })
+                    .collect()
             }
             WeaponType::Sword => {
-                // Arc slash in front of ship with limited lifetime
-                vec![Projectile::new_with_type(
-                    center_x,
-                    fire_y.saturating_sub(1),
-                    ProjectileOwner::Player,
-                    ProjectileType::Slash,
-                    0,
-                    Some(10), // Slash lasts 10 frames
+                // Arc slash anchored to the ship, sweeping left to right
+                // Each level extends the reach of the arc
+                let (anchor_x, anchor_y) = self.get_slash_anchor();
+                vec![Projectile::new_slash(
+                    anchor_x,
+                    anchor_y,
+                    SLASH_RADIUS + (level as u16 - 1),
+                    self.id,
                 )]
             }
             WeaponType::Bug => {
-                // Dual angled shots in V-pattern
-                vec![
-                    // Left diagonal shot
-                    Projectile::new_with_type(
-                        center_x,
-                        fire_y,
-                        ProjectileOwner::Player,
-                        ProjectileType::BugShot,
-                        -1, // Move left
-                        None,
-                    ),
-                    // Right diagonal shot
-                    Projectile::new_with_type(
-                        center_x,
-                        fire_y,
-                        ProjectileOwner::Player,
-                        ProjectileType::BugShot,
-                        1, // Move right
-                        None,
-                    ),
-                ]
+                // Angled shots in a V-pattern, adding spread angles with each level
+                let velocities: &[i16] = match level {
+                    1 => &[-1, 1],
+                    2 => &[-1, 0, 1],
+                    3 => &[-2, -1, 1, 2],
+                    4 => &[-2, -1, 0, 1, 2],
+                    _ => &[-3, -2, -1, 1, 2, 3],
+                };
+                // Focus narrows the V to the shallowest angles
+                let velocities: Vec<i16> = if self.focused {
+                    let mut narrowed: Vec<i16> =
+                        velocities.iter().map(|&v| v.clamp(-1, 1)).collect();
+                    narrowed.dedup();
+                    narrowed
+                } else {
+                    velocities.to_vec()
+                };
+                velocities
+                    .into_iter()
+                    .map(|velocity_x| {
+                        Projectile::new_with_type(
+                            center_x,
+                            fire_y,
+                            ProjectileOwner::Player(self.id),
+                            ProjectileType::BugShot,
+                            velocity_x,
+                            None,
+                        )
+                    })
+                    .collect()
             }
             WeaponType::Bomber => {
                 // Slow-moving bomb that explodes after a short time
-                vec![Projectile::new_with_damage(
+                // Higher levels widen the blast and release cluster bomblets from level 3
+                vec![Projectile::new_bomb(
                     center_x,
                     fire_y,
-                    ProjectileOwner::Player,
-                    ProjectileType::BomberProjectile,
-                    0,
-                    Some(90), // Bomb lasts 90 frames (~1.5 seconds) before exploding
-                    5,        // Direct hit does only 5 damage, explosion does AoE damage
+                    90, // Bomb lasts 90 frames (~1.5 seconds) before exploding
+                    BOMB_RADIUS + (level as u16 - 1) * 2,
+                    level.saturating_sub(2) * 2,
+                    self.id,
                 )]
             }
+            WeaponType::Homing => {
+                // Missiles launch straight up, then up-left and up-right as levels add more
+                let headings: &[u8] = match level {
+                    1 | 2 => &[0],
+                    3 | 4 => &[7, 1],
+                    _ => &[7, 0, 1],
+                };
+                if self.focused {
+                    // Focus launches every missile straight up in a tight column group
+                    let count = headings.len() as i16;
+                    (0..count)
+                        .map(|i| {
+                            let offset = i * 2 - (count - 1);
+                            let x = (center_x as i16 + offset).max(0) as u16;
+                            Projectile::new_missile(x, fire_y, 0, self.id)
+                        })
+                        .collect()
+                } else {
+                    headings
+                        .iter()
+                        .map(|&heading| Projectile::new_missile(center_x, fire_y, heading, self.id))
+                        .collect()
+                }
+            }
+            WeaponType::Laser => {
+                // The beam is cast each frame by the game loop via fire_laser
+                vec![]
+            }
         }
     }
 
+    /// Fires the laser for one frame, building heat
+    /// Returns false if the laser is locked out from overheating
+    pub fn fire_laser(&mut self) -> bool {
+        if self.laser_overheated {
+            return false;
+        }
+
+        if !self.laser_burst {
+            self.laser_burst_landed = false;
+        }
+        self.laser_firing = true;
+        self.laser_heat = (self.laser_heat + 1).min(LASER_MAX_HEAT);
+        if self.laser_heat == LASER_MAX_HEAT {
+            self.laser_overheated = true;
+        }
+        true
+    }
+
+    /// Whether this frame's beam starts a new burst rather than continuing a held one
+    pub fn is_laser_burst_start(&self) -> bool {
+        !self.laser_burst
+    }
+
+    /// Notes that the current burst struck an enemy
+    /// Returns false if it already had, so a burst counts as one hit
+    pub fn land_laser_hit(&mut self) -> bool {
+        !std::mem::replace(&mut self.laser_burst_landed, true)
+    }
+
     pub fn change_weapon(&mut self, weapon_type: WeaponType) {
         self.current_weapon = weapon_type;
     }
+
+    /// Applies a weapon pickup: the same weapon levels up, a different one
+    /// switches weapons at one level lower
+    pub fn collect_weapon(&mut self, weapon_type: WeaponType) {
+        if weapon_type == self.current_weapon {
+            self.weapon_level = (self.weapon_level + 1).min(MAX_WEAPON_LEVEL);
+        } else {
+            self.change_weapon(weapon_type);
+            self.weapon_level = self.weapon_level.saturating_sub(1).max(1);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::entities::projectile::MISSILE_LIFETIME;
+
+    fn bounds(min_x: u16, max_x: u16, min_y: u16, max_y: u16) -> MovementBounds {
+        MovementBounds {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
 
     #[test]
     fn test_player_new() {
@@ -208,51 +675,188 @@ mod tests {
     #[test]
     fn test_player_movement_left() {
         let mut player = Player::new(10, 10);
-        player.move_left(0);
+        player.steer(-1, 0, MovementModel::Arcade, &bounds(0, 79, 0, 23));
         assert_eq!(player.x, 9);
 
         // Test boundary
         player.x = 0;
-        player.move_left(0);
+        player.steer(-1, 0, MovementModel::Arcade, &bounds(0, 79, 0, 23));
         assert_eq!(player.x, 0);
     }
 
     #[test]
     fn test_player_movement_right() {
         let mut player = Player::new(10, 10);
-        player.move_right(79);
+        player.steer(1, 0, MovementModel::Arcade, &bounds(0, 79, 0, 23));
         assert_eq!(player.x, 11);
 
         // Test boundary
         player.x = 79;
-        player.move_right(79);
+        player.steer(1, 0, MovementModel::Arcade, &bounds(0, 79, 0, 23));
         assert_eq!(player.x, 79);
     }
 
     #[test]
     fn test_player_movement_up() {
         let mut player = Player::new(10, 10);
-        player.move_up(0);
+        player.steer(0, -1, MovementModel::Arcade, &bounds(0, 79, 0, 23));
         assert_eq!(player.y, 9);
 
         // Test boundary
         player.y = 0;
-        player.move_up(0);
+        player.steer(0, -1, MovementModel::Arcade, &bounds(0, 79, 0, 23));
         assert_eq!(player.y, 0);
     }
 
     #[test]
     fn test_player_movement_down() {
         let mut player = Player::new(10, 10);
-        player.move_down(23);
+        player.steer(0, 1, MovementModel::Arcade, &bounds(0, 79, 0, 23));
         assert_eq!(player.y, 11);
 
         // Test boundary
         player.y = 23;
-        player.move_down(23);
+        player.steer(0, 1, MovementModel::Arcade, &bounds(0, 79, 0, 23));
         assert_eq!(player.y, 23);
     }
 
+    #[test]
+    fn test_player_focus_moves_in_sub_steps() {
+        let mut player = Player::new(10, 10);
+        player.focused = true;
+
+        // A quarter cell per frame: three frames stay put, the fourth moves
+        for _ in 0..3 {
+            player.steer(1, 0, MovementModel::Arcade, &bounds(0, 79, 0, 23));
+        }
+        assert_eq!(player.x, 10);
+        player.steer(1, 0, MovementModel::Arcade, &bounds(0, 79, 0, 23));
+        assert_eq!(player.x, 11);
+
+        player.focused = false;
+        player.steer(0, -1, MovementModel::Arcade, &bounds(0, 79, 0, 23));
+        assert_eq!(player.y, 9);
+    }
+
+    #[test]
+    fn test_player_focus_tightens_fire_pattern() {
+        let mut player = Player::new(40, 20);
+        player.weapon_level = 3;
+        let spread: Vec<u16> = player.try_fire().iter().map(|p| p.x).collect();
+
+        player.focused = true;
+        player.fire_cooldown = 0;
+        let focused: Vec<u16> = player.try_fire().iter().map(|p| p.x).collect();
+
+        assert_eq!(focused.len(), spread.len());
+        let width = |xs: &[u16]| xs.iter().max().unwrap() - xs.iter().min().unwrap();
+        assert!(width(&focused) < width(&spread));
+
+        player.current_weapon = WeaponType::Bug;
+        player.weapon_level = 5;
+        player.fire_cooldown = 0;
+        let shots = player.try_fire();
+        assert!(shots.iter().all(|p| p.velocity_x.abs() <= 1));
+    }
+
+    #[test]
+    fn test_player_arcade_steer_is_instant() {
+        let bounds = bounds(0, 79, 0, 23);
+        let mut player = Player::new(10, 10);
+        player.steer(1, 0, MovementModel::Arcade, &bounds);
+        assert_eq!(player.x, 11);
+
+        // Releasing stops immediately
+        player.steer(0, 0, MovementModel::Arcade, &bounds);
+        assert_eq!(player.x, 11);
+        assert_eq!(player.velocity_x, 0);
+    }
+
+    #[test]
+    fn test_player_diagonal_is_not_faster() {
+        let bounds = bounds(0, 79, 0, 23);
+        let mut straight = Player::new(10, 10);
+        let mut diagonal = Player::new(10, 10);
+        for _ in 0..8 {
+            straight.steer(1, 0, MovementModel::Arcade, &bounds);
+            diagonal.steer(1, 1, MovementModel::Arcade, &bounds);
+        }
+        assert_eq!(straight.x, 18);
+        assert!(diagonal.x < 18);
+        assert_eq!(diagonal.x, diagonal.y);
+    }
+
+    #[test]
+    fn test_player_inertial_accelerates_and_drifts() {
+        let bounds = bounds(0, 79, 0, 23);
+        let mut player = Player::new(10, 10);
+        player.steer(1, 0, MovementModel::Inertial, &bounds);
+        assert!(player.velocity_x > 0 && player.velocity_x < player.get_move_speed());
+
+        for _ in 0..10 {
+            player.steer(1, 0, MovementModel::Inertial, &bounds);
+        }
+        assert_eq!(player.velocity_x, player.get_move_speed());
+
+        // Coasting keeps moving, then drag brings the ship to rest
+        let x_at_release = player.x;
+        player.steer(0, 0, MovementModel::Inertial, &bounds);
+        assert!(player.velocity_x > 0);
+        for _ in 0..20 {
+            player.steer(0, 0, MovementModel::Inertial, &bounds);
+        }
+        assert_eq!(player.velocity_x, 0);
+        assert!(player.x > x_at_release);
+    }
+
+    #[test]
+    fn test_player_inertial_stops_at_wall() {
+        let bounds = bounds(0, 12, 0, 23);
+        let mut player = Player::new(10, 10);
+        for _ in 0..30 {
+            player.steer(1, 0, MovementModel::Inertial, &bounds);
+        }
+        assert_eq!(player.x, 12);
+        assert_eq!(player.velocity_x, 0);
+    }
+
+    #[test]
+    fn test_player_new_with_ship_uses_ship_stats() {
+        for (index, ship) in SHIPS.iter().enumerate() {
+            let player = Player::new_with_ship(40, 20, index);
+            assert_eq!(player.health, ship.max_health);
+            assert_eq!(player.max_health, ship.max_health);
+            assert_eq!(player.current_weapon, ship.starting_weapon);
+            assert_eq!(player.get_width(), ship.width);
+            assert_eq!(player.get_height(), ship.height);
+            assert_eq!(player.handling, ship.handling);
+        }
+    }
+
+    #[test]
+    fn test_player_ship_passives() {
+        let ship_with = |passive: ShipPassive| {
+            let index = SHIPS.iter().position(|s| s.passive == passive).unwrap();
+            Player::new_with_ship(40, 20, index)
+        };
+
+        let mut armored = ship_with(ShipPassive::Armored);
+        armored.take_damage(20);
+        assert_eq!(armored.health, armored.max_health - 10);
+
+        let mut rapid = ship_with(ShipPassive::RapidFire);
+        let mut baseline = Player::new(40, 20);
+        rapid.current_weapon = WeaponType::BasicGun;
+        rapid.reset_cooldown();
+        baseline.reset_cooldown();
+        assert!(rapid.fire_cooldown < baseline.fire_cooldown);
+
+        let mut bomb_bay = ship_with(ShipPassive::BombBay);
+        assert_eq!(bomb_bay.bombs, STARTING_BOMBS + 1);
+        while bomb_bay.add_bomb() {}
+        assert_eq!(bomb_bay.bombs, MAX_BOMBS + 2);
+    }
+
     #[test]
     fn test_player_fire_cooldown() {
         let mut player = Player::new(10, 10);
@@ -286,7 +890,7 @@ mod tests {
         let mut player = Player::new(10, 10);
         let projectiles = player.try_fire();
         assert_eq!(projectiles.len(), 1);
-        assert_eq!(projectiles[0].owner, ProjectileOwner::Player);
+        assert_eq!(projectiles[0].owner, ProjectileOwner::Player(0));
         assert_eq!(projectiles[0].projectile_type, ProjectileType::Bullet);
     }
 
@@ -298,6 +902,10 @@ mod tests {
         assert_eq!(projectiles.len(), 1);
         assert_eq!(projectiles[0].projectile_type, ProjectileType::Slash);
         assert_eq!(projectiles[0].lifetime, Some(10));
+        assert_eq!(
+            (projectiles[0].x, projectiles[0].y),
+            player.get_slash_anchor()
+        );
     }
 
     #[test]
@@ -310,6 +918,275 @@ mod tests {
         assert_eq!(projectiles[1].velocity_x, 1);
     }
 
+    #[test]
+    fn test_player_collect_same_weapon_levels_up() {
+        let mut player = Player::new(10, 10);
+        assert_eq!(player.weapon_level, 1);
+
+        player.collect_weapon(WeaponType::BasicGun);
+        assert_eq!(player.weapon_level, 2);
+
+        // Level is capped
+        for _ in 0..10 {
+            player.collect_weapon(WeaponType::BasicGun);
+        }
+        assert_eq!(player.weapon_level, MAX_WEAPON_LEVEL);
+    }
+
+    #[test]
+    fn test_player_collect_different_weapon_drops_level() {
+        let mut player = Player::new(10, 10);
+        player.weapon_level = 3;
+
+        player.collect_weapon(WeaponType::Bug);
+        assert_eq!(player.current_weapon, WeaponType::Bug);
+        assert_eq!(player.weapon_level, 2);
+
+        // Never drops below level 1
+        player.weapon_level = 1;
+        player.collect_weapon(WeaponType::Sword);
+        assert_eq!(player.weapon_level, 1);
+    }
+
+    #[test]
+    fn test_player_damage_loses_weapon_level() {
+        let mut player = Player::new(10, 10);
+        player.weapon_level = 2;
+        player.take_damage(10);
+        assert_eq!(player.weapon_level, 1);
+        player.take_damage(10);
+        assert_eq!(player.weapon_level, 1);
+    }
+
+    #[test]
+    fn test_player_weapon_levels_change_fire_pattern() {
+        let mut player = Player::new(10, 10);
+        player.weapon_level = 3;
+        let projectiles = player.try_fire();
+        assert_eq!(projectiles.len(), 3);
+        let xs: Vec<u16> = projectiles.iter().map(|p| p.x).collect();
+        assert_eq!(xs, vec![15, 17, 19]);
+
+        player.fire_cooldown = 0;
+        player.change_weapon(WeaponType::Bug);
+        player.weapon_level = MAX_WEAPON_LEVEL;
+        assert_eq!(player.try_fire().len(), 6);
+
+        player.fire_cooldown = 0;
+        player.change_weapon(WeaponType::Sword);
+        let projectiles = player.try_fire();
+        assert_eq!(projectiles[0].radius, SLASH_RADIUS + 4);
+
+        player.fire_cooldown = 0;
+        player.change_weapon(WeaponType::Bomber);
+        let projectiles = player.try_fire();
+        assert_eq!(projectiles[0].radius, BOMB_RADIUS + 8);
+        assert_eq!(projectiles[0].bomblets, 6);
+    }
+
+    #[test]
+    fn test_player_try_fire_homing() {
+        let mut player = Player::new(10, 10);
+        player.change_weapon(WeaponType::Homing);
+        let projectiles = player.try_fire();
+        assert_eq!(projectiles.len(), 1);
+        assert_eq!(projectiles[0].projectile_type, ProjectileType::Missile);
+        assert_eq!(projectiles[0].lifetime, Some(MISSILE_LIFETIME));
+        assert_eq!(projectiles[0].target_id, None);
+    }
+
+    #[test]
+    fn test_player_laser_overheats_and_cools() {
+        let mut player = Player::new(10, 10);
+        player.change_weapon(WeaponType::Laser);
+        assert!(player.try_fire().is_empty());
+
+        for _ in 0..LASER_MAX_HEAT {
+            assert!(player.fire_laser());
+            player.update_cooldown();
+        }
+        assert!(player.laser_overheated);
+        assert!(!player.fire_laser());
+
+        // Locked out until fully cooled
+        player.update_cooldown();
+        assert_eq!(player.laser_heat, LASER_MAX_HEAT - LASER_COOL_RATE);
+        assert!(!player.fire_laser());
+
+        while player.laser_heat > 0 {
+            player.update_cooldown();
+        }
+        assert!(!player.laser_overheated);
+        assert!(player.fire_laser());
+    }
+
+    #[test]
+    fn test_player_laser_burst_lasts_while_held() {
+        let mut player = Player::new(10, 10);
+        player.change_weapon(WeaponType::Laser);
+
+        assert!(player.fire_laser());
+        assert!(player.is_laser_burst_start());
+        assert!(player.land_laser_hit());
+        player.update_cooldown();
+
+        // Still held: same burst, so striking again isn't a new hit
+        assert!(player.fire_laser());
+        assert!(!player.is_laser_burst_start());
+        assert!(!player.land_laser_hit());
+        player.update_cooldown();
+
+        // Released for a frame, then pressed again
+        player.update_cooldown();
+        assert!(player.fire_laser());
+        assert!(player.is_laser_burst_start());
+        assert!(player.land_laser_hit());
+    }
+
+    #[test]
+    fn test_player_heal_caps_at_max_health() {
+        let mut player = Player::new(10, 10);
+        player.take_damage(30);
+        player.heal(20);
+        assert_eq!(player.health, 90);
+        player.heal(50);
+        assert_eq!(player.health, player.max_health);
+    }
+
+    #[test]
+    fn test_player_shield_blocks_damage() {
+        let mut player = Player::new(10, 10);
+        player.weapon_level = 2;
+        player.activate_shield(2, SHIELD_HITS);
+        player.take_damage(30);
+        assert_eq!(player.health, 100);
+        assert_eq!(player.weapon_level, 2);
+
+        player.update_cooldown();
+        player.update_cooldown();
+        assert!(!player.is_shielded());
+        player.take_damage(30);
+        assert_eq!(player.health, 70);
+    }
+
+    #[test]
+    fn test_player_shield_breaks_after_hits() {
+        let mut player = Player::new(10, 10);
+        player.activate_shield(SHIELD_DURATION_FRAMES, SHIELD_HITS);
+        for _ in 0..SHIELD_HITS - 1 {
+            player.take_damage(10);
+            assert!(player.is_shielded());
+        }
+        // Last hit left makes the shield flash
+        assert!(player.is_shield_expiring());
+
+        player.take_damage(10);
+        assert!(!player.is_shielded());
+        assert_eq!(player.health, 100);
+
+        player.take_damage(10);
+        assert_eq!(player.health, 90);
+    }
+
+    #[test]
+    fn test_player_shield_expiring_when_time_runs_low() {
+        let mut player = Player::new(10, 10);
+        player.activate_shield(SHIELD_WARNING_FRAMES + 1, SHIELD_HITS);
+        assert!(!player.is_shield_expiring());
+        player.update_cooldown();
+        assert!(player.is_shield_expiring());
+    }
+
+    #[test]
+    fn test_player_bomb_capacity() {
+        let mut player = Player::new(10, 10);
+        assert_eq!(player.bombs, STARTING_BOMBS);
+        for _ in STARTING_BOMBS..MAX_BOMBS {
+            assert!(player.add_bomb());
+        }
+        assert!(!player.add_bomb());
+        assert_eq!(player.bombs, MAX_BOMBS);
+    }
+
+    #[test]
+    fn test_player_use_bomb_grants_invulnerability() {
+        let mut player = Player::new(10, 10);
+        player.bombs = 1;
+        assert!(player.use_bomb());
+        assert_eq!(player.bombs, 0);
+        assert!(player.is_invulnerable());
+
+        player.take_damage(30);
+        assert_eq!(player.health, 100);
+
+        // No charges left
+        assert!(!player.use_bomb());
+
+        for _ in 0..BOMB_INVULNERABILITY_FRAMES {
+            player.update_cooldown();
+        }
+        assert!(!player.is_invulnerable());
+        player.take_damage(30);
+        assert_eq!(player.health, 70);
+    }
+
+    #[test]
+    fn test_player_dash_moves_and_cools_down() {
+        let bounds = bounds(0, 200, 0, 100);
+        let mut player = Player::new(40, 20);
+        assert!(!player.try_dash(0, 0));
+        assert!(player.try_dash(1, 0));
+        assert!(player.is_dashing());
+        assert!(player.is_invulnerable());
+        // Can't dash again mid-dash
+        assert!(!player.try_dash(-1, 0));
+
+        for _ in 0..DASH_FRAMES {
+            player.update_dash(&bounds);
+        }
+        assert_eq!(player.x, 40 + DASH_FRAMES as u16 * DASH_SPEED);
+        assert_eq!(player.y, 20);
+        assert_eq!(
+            player.dash_state,
+            DashState::Cooldown {
+                frames_left: DASH_COOLDOWN_FRAMES
+            }
+        );
+        assert!(!player.try_dash(1, 0));
+
+        for _ in 0..DASH_COOLDOWN_FRAMES {
+            player.update_dash(&bounds);
+        }
+        assert_eq!(player.dash_state, DashState::Ready);
+        assert!(player.try_dash(0, -1));
+    }
+
+    #[test]
+    fn test_player_dash_respects_bounds() {
+        let bounds = bounds(0, 200, 0, 22);
+        let mut player = Player::new(5, 20);
+        player.try_dash(-1, 1);
+        for _ in 0..DASH_FRAMES {
+            player.update_dash(&bounds);
+        }
+        assert_eq!(player.x, 0);
+        assert_eq!(player.y, 22);
+    }
+
+    #[test]
+    fn test_player_speed_boost_doubles_movement() {
+        let mut player = Player::new(10, 10);
+        player.activate_speed_boost(SPEED_BOOST_FRAMES);
+        player.steer(1, 0, MovementModel::Arcade, &bounds(0, 79, 0, 23));
+        assert_eq!(player.x, 12);
+
+        // Boosted movement still respects bounds
+        player.steer(-1, 0, MovementModel::Arcade, &bounds(11, 79, 0, 23));
+        assert_eq!(player.x, 11);
+        player.steer(-1, 0, MovementModel::Arcade, &bounds(11, 79, 0, 23));
+        assert_eq!(player.x, 11);
+    }
+
     #[test]
     fn test_player_cooldown_prevents_firing() {
         let mut player = Player::new(10, 10);
@@ -352,15 +1229,14 @@ mod tests {
             #[test]
             fn test_player_stays_in_bounds_x(
                 initial_x in 0u16..80,
-                moves in prop::collection::vec(prop::bool::ANY, 0..100)
+                moves in prop::collection::vec(prop::bool::ANY, 0..100),
+                inertial in prop::bool::ANY
             ) {
+                let model = if inertial { MovementModel::Inertial } else { MovementModel::Arcade };
                 let mut player = Player::new(initial_x, 10);
                 for move_right in moves {
-                    if move_right {
-                        player.move_right(79);
-                    } else {
-                        player.move_left(0);
-                    }
+                    let dx = if move_right { 1 } else { -1 };
+                    player.steer(dx, 0, model, &bounds(0, 79, 0, 23));
                 }
                 prop_assert!(player.x <= 79);
             }
@@ -368,15 +1244,14 @@ mod tests {
             #[test]
             fn test_player_stays_in_bounds_y(
                 initial_y in 0u16..24,
-                moves in prop::collection::vec(prop::bool::ANY, 0..100)
+                moves in prop::collection::vec(prop::bool::ANY, 0..100),
+                inertial in prop::bool::ANY
             ) {
+                let model = if inertial { MovementModel::Inertial } else { MovementModel::Arcade };
                 let mut player = Player::new(10, initial_y);
                 for move_down in moves {
-                    if move_down {
-                        player.move_down(23);
-                    } else {
-                        player.move_up(0);
-                    }
+                    let dy = if move_down { 1 } else { -1 };
+                    player.steer(0, dy, model, &bounds(0, 79, 0, 23));
                 }
                 prop_assert!(player.y <= 23);
             }
@@ -1,15 +1,75 @@
+use super::enemy::Enemy;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ProjectileOwner {
-    Player,
+    /// Fired by the player with this id (0 for player one, 1 for player two)
+    Player(usize),
     Enemy,
 }
 
+impl ProjectileOwner {
+    /// Whether one of the players fired the projectile
+    pub fn is_player(&self) -> bool {
+        matches!(self, ProjectileOwner::Player(_))
+    }
+
+    /// Id of the player who fired the projectile, None for enemy fire
+    pub fn get_player_id(&self) -> Option<usize> {
+        match self {
+            ProjectileOwner::Player(id) => Some(*id),
+            ProjectileOwner::Enemy => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ProjectileType {
     Bullet,
     Slash,
     BugShot,
     BomberProjectile,
+    Missile,
+}
+
+/// Number of frames a sword slash takes to sweep across its arc
+pub const SLASH_LIFETIME: u8 = 10;
+
+/// Default reach of a sword slash in rows above the ship
+pub const SLASH_RADIUS: u16 = 6;
+
+/// Default blast radius of a bomb
+pub const BOMB_RADIUS: u16 = 8;
+
+/// Number of frames a homing missile flies before burning out
+pub const MISSILE_LIFETIME: u8 = 120;
+
+/// Frames between each 45 degree turn of a homing missile
+pub const MISSILE_TURN_INTERVAL: u8 = 3;
+
+/// Movement per frame for each missile heading, clockwise from straight up
+const HEADINGS: [(i16, i16); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Enemy projectile types that player shots can destroy by default
+pub const SHOOTABLE_ENEMY_PROJECTILES: &[ProjectileType] = &[ProjectileType::Bullet];
+
+/// Outcome of a player projectile meeting an enemy projectile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectileInteraction {
+    /// Projectiles pass through each other
+    None,
+    /// Enemy projectile is turned around and becomes a player projectile
+    Deflect,
+    /// Both projectiles are destroyed
+    Cancel,
 }
 
 #[derive(Debug, Clone)]
@@ -21,12 +81,25 @@ pub struct Projectile {
     pub projectile_type: ProjectileType,
     pub velocity_x: i16,
     pub lifetime: Option<u8>,
+    /// Reach of area-effect projectiles (sword arc radius, bomb blast radius)
+    pub radius: u16,
+    /// Number of smaller bombs released when this bomb explodes
+    pub bomblets: u8,
+    /// Ids of enemies already hit by a piercing projectile
+    pub hit_enemies: Vec<u32>,
+    /// Enemy a homing missile is currently chasing
+    pub target_id: Option<u32>,
+    /// Direction a homing missile is flying in, as an index into the 8 compass headings
+    pub heading: u8,
+    /// Enemy fire turned around by a sword slash; the player never fired it, so its hits
+    /// don't count toward accuracy
+    pub deflected: bool,
 }
 
 impl Projectile {
     pub fn new(x: u16, y: u16, owner: ProjectileOwner) -> Self {
         let damage = match owner {
-            ProjectileOwner::Player => 10,
+            ProjectileOwner::Player(_) => 10,
             ProjectileOwner::Enemy => 10,
         };
 
@@ -38,6 +111,12 @@ impl Projectile {
             projectile_type: ProjectileType::Bullet,
             velocity_x: 0,
             lifetime: None,
+            radius: 0,
+            bomblets: 0,
+            hit_enemies: Vec::new(),
+            target_id: None,
+            heading: 0,
+            deflected: false,
         }
     }
 
@@ -50,7 +129,7 @@ impl Projectile {
         lifetime: Option<u8>,
     ) -> Self {
         let damage = match owner {
-            ProjectileOwner::Player => 10,
+            ProjectileOwner::Player(_) => 10,
             ProjectileOwner::Enemy => 10,
         };
 
@@ -62,6 +141,12 @@ impl Projectile {
             projectile_type,
             velocity_x,
             lifetime,
+            radius: 0,
+            bomblets: 0,
+            hit_enemies: Vec::new(),
+            target_id: None,
+            heading: 0,
+            deflected: false,
         }
     }
 
@@ -82,9 +167,145 @@ impl Projectile {
             projectile_type,
             velocity_x,
             lifetime,
+            radius: 0,
+            bomblets: 0,
+            hit_enemies: Vec::new(),
+            target_id: None,
+            heading: 0,
+            deflected: false,
         }
     }
 
+    /// Creates a sword slash anchored at the given point above a player's ship
+    pub fn new_slash(x: u16, y: u16, radius: u16, player: usize) -> Self {
+        let mut slash = Self::new_with_type(
+            x,
+            y,
+            ProjectileOwner::Player(player),
+            ProjectileType::Slash,
+            0,
+            Some(SLASH_LIFETIME),
+        );
+        slash.radius = radius;
+        slash
+    }
+
+    /// Creates a player bomb that explodes once its lifetime runs out
+    pub fn new_bomb(
+        x: u16,
+        y: u16,
+        lifetime: u8,
+        radius: u16,
+        bomblets: u8,
+        player: usize,
+    ) -> Self {
+        let mut bomb = Self::new_with_damage(
+            x,
+            y,
+            ProjectileOwner::Player(player),
+            ProjectileType::BomberProjectile,
+            0,
+            Some(lifetime),
+            5, // Direct hit does only 5 damage, explosion does AoE damage
+        );
+        bomb.radius = radius;
+        bomb.bomblets = bomblets;
+        bomb
+    }
+
+    /// Creates a homing missile launched with the given heading (0 is straight up)
+    pub fn new_missile(x: u16, y: u16, heading: u8, player: usize) -> Self {
+        let mut missile = Self::new_with_damage(
+            x,
+            y,
+            ProjectileOwner::Player(player),
+            ProjectileType::Missile,
+            0,
+            Some(MISSILE_LIFETIME),
+            15,
+        );
+        missile.heading = heading % HEADINGS.len() as u8;
+        missile
+    }
+
+    /// Smaller bombs scattered sideways when a cluster bomb explodes, owned by the same player
+    pub fn spawn_bomblets(&self) -> Vec<Projectile> {
+        let player = self.owner.get_player_id().unwrap_or_default();
+        (0..self.bomblets)
+            .map(|i| {
+                // Alternate left and right, spreading further with each pair
+                let spread = (i / 2 + 1) as i16;
+                let velocity_x = if i % 2 == 0 { -spread } else { spread };
+                let mut bomblet =
+                    Projectile::new_bomb(self.x, self.y, 20, self.radius / 2, 0, player);
+                bomblet.velocity_x = velocity_x;
+                bomblet
+            })
+            .collect()
+    }
+
+    /// Updates the projectile with knowledge of enemy positions, letting
+    /// homing missiles pick and chase targets before moving
+    pub fn update_with_targets(&mut self, enemies: &[Enemy]) {
+        if self.projectile_type == ProjectileType::Missile {
+            self.acquire_target(enemies);
+            self.steer(enemies);
+        }
+        self.update();
+    }
+
+    /// Locks onto the nearest living enemy if there is no target or the current one died
+    fn acquire_target(&mut self, enemies: &[Enemy]) {
+        let target_alive = self
+            .target_id
+            .is_some_and(|id| enemies.iter().any(|e| e.id == id && e.is_alive()));
+        if target_alive {
+            return;
+        }
+
+        self.target_id = enemies
+            .iter()
+            .filter(|e| e.is_alive())
+            .min_by_key(|e| {
+                let dx = (e.x + e.get_width() / 2) as i32 - self.x as i32;
+                let dy = (e.y + e.get_height() / 2) as i32 - self.y as i32;
+                dx * dx + dy * dy
+            })
+            .map(|e| e.id);
+    }
+
+    /// Turns one heading step toward the target, limited by the missile turn rate
+    fn steer(&mut self, enemies: &[Enemy]) {
+        if self
+            .lifetime
+            .is_some_and(|l| !l.is_multiple_of(MISSILE_TURN_INTERVAL))
+        {
+            return;
+        }
+        let Some(target) = self
+            .target_id
+            .and_then(|id| enemies.iter().find(|e| e.id == id))
+        else {
+            return;
+        };
+
+        let dx = (target.x + target.get_width() / 2) as f32 - self.x as f32;
+        let dy = (target.y + target.get_height() / 2) as f32 - self.y as f32;
+        if dx == 0.0 && dy == 0.0 {
+            return;
+        }
+
+        // Heading 0 points up and headings go clockwise in 45 degree steps
+        let angle = dx.atan2(-dy).to_degrees().rem_euclid(360.0);
+        let desired = ((angle / 45.0).round() as u8) % 8;
+        let diff = (desired + 8 - self.heading) % 8;
+        self.heading = match diff {
+            0 => self.heading,
+            1..=4 => (self.heading + 1) % 8,
+            _ => (self.heading + 7) % 8,
+        };
+    }
+
     pub fn update(&mut self) {
         // Update lifetime
         if let Some(ref mut lifetime) = self.lifetime
@@ -92,18 +313,27 @@ impl Projectile {
                 *lifetime -= 1;
             }
 
+        // Missiles fly along their heading instead of straight up or down
+        if self.projectile_type == ProjectileType::Missile {
+            let (dx, dy) = HEADINGS[self.heading as usize % HEADINGS.len()];
+            self.x = (self.x as i16 + dx).max(0) as u16;
+            self.y = (self.y as i16 + dy).max(0) as u16;
+            return;
+        }
+
         // Update vertical position
         // Bomber projectiles move slower (every 3rd frame)
-        let should_move = if self.projectile_type == ProjectileType::BomberProjectile {
+        let should_move = match self.projectile_type {
             // Use lifetime to determine movement (move on frames where lifetime % 3 == 0)
-            self.lifetime.is_none_or(|l| l % 3 == 0)
-        } else {
-            true
+            ProjectileType::BomberProjectile => self.lifetime.is_none_or(|l| l % 3 == 0),
+            // Slashes are anchored to the ship and moved by the game loop
+            ProjectileType::Slash => false,
+            _ => true,
         };
 
         if should_move {
             match self.owner {
-                ProjectileOwner::Player => {
+                ProjectileOwner::Player(_) => {
                     if self.y > 0 {
                         self.y -= 1;
                     }
@@ -123,6 +353,105 @@ impl Projectile {
         }
     }
 
+    /// Whether this projectile passes through enemies instead of stopping at the first hit
+    pub fn pierces(&self) -> bool {
+        self.projectile_type == ProjectileType::Slash
+    }
+
+    /// Moves an anchored projectile (sword slash) along with the ship
+    pub fn follow(&mut self, anchor_x: u16, anchor_y: u16) {
+        self.x = anchor_x;
+        self.y = anchor_y;
+    }
+
+    /// Cells currently covered by this projectile.
+    /// Slashes cover the part of their arc swept in the last few frames, ordered so
+    /// the leading edge comes last; everything else covers a single cell.
+    pub fn hit_cells(&self) -> Vec<(u16, u16)> {
+        if self.projectile_type != ProjectileType::Slash {
+            return vec![(self.x, self.y)];
+        }
+
+        let offsets = slash_arc_offsets(self.radius);
+        let elapsed = SLASH_LIFETIME.saturating_sub(self.lifetime.unwrap_or(0)) as usize;
+        let lead = ((elapsed + 1) * offsets.len() / SLASH_LIFETIME as usize).min(offsets.len());
+        let trail = (offsets.len() / 3).max(1);
+
+        offsets[lead.saturating_sub(trail)..lead]
+            .iter()
+            .filter_map(|(dx, dy)| {
+                let x = self.x as i16 + dx;
+                let y = self.y as i16 + dy;
+                (x >= 0 && y >= 0).then_some((x as u16, y as u16))
+            })
+            .collect()
+    }
+
+    /// Checks whether any covered cell lies inside the given rectangle
+    pub fn overlaps_rect(&self, x: u16, y: u16, width: u16, height: u16) -> bool {
+        self.hit_cells()
+            .iter()
+            .any(|&(cx, cy)| cx >= x && cx < x + width && cy >= y && cy < y + height)
+    }
+
+    /// Bombs explode on the frame their lifetime runs out
+    pub fn is_exploding(&self) -> bool {
+        self.projectile_type == ProjectileType::BomberProjectile && self.lifetime == Some(0)
+    }
+
+    /// Checks whether two projectiles touch or have just crossed each other.
+    /// Player and enemy projectiles move in opposite directions, so after an
+    /// update they may have swapped rows without ever sharing a cell.
+    pub fn intersects(&self, other: &Projectile) -> bool {
+        // Slashes cover an arc rather than a single moving cell
+        if self.projectile_type == ProjectileType::Slash {
+            return self.overlaps_rect(other.x, other.y, 1, 1);
+        }
+        if other.projectile_type == ProjectileType::Slash {
+            return other.overlaps_rect(self.x, self.y, 1, 1);
+        }
+
+        let (upper, lower) = if self.y <= other.y {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let crossed = upper.owner.is_player()
+            && lower.owner == ProjectileOwner::Enemy
+            && lower.y == upper.y + 1;
+        let max_dx = self.velocity_x.unsigned_abs() + other.velocity_x.unsigned_abs();
+
+        (self.y == other.y || crossed) && self.x.abs_diff(other.x) <= max_dx
+    }
+
+    /// Determines what happens when this player projectile meets an enemy projectile.
+    /// `shootable` lists the enemy projectile types that regular shots can destroy.
+    pub fn interaction_with(
+        &self,
+        other: &Projectile,
+        shootable: &[ProjectileType],
+    ) -> ProjectileInteraction {
+        if !self.owner.is_player() || other.owner != ProjectileOwner::Enemy {
+            return ProjectileInteraction::None;
+        }
+
+        match self.projectile_type {
+            // Sword slashes bat any enemy projectile back
+            ProjectileType::Slash => ProjectileInteraction::Deflect,
+            // Bombs only clear projectiles when they explode
+            ProjectileType::BomberProjectile => ProjectileInteraction::None,
+            _ if shootable.contains(&other.projectile_type) => ProjectileInteraction::Cancel,
+            _ => ProjectileInteraction::None,
+        }
+    }
+
+    /// Turns an enemy projectile around so it travels upward as a shot of the given player
+    pub fn deflect(&mut self, player: usize) {
+        self.owner = ProjectileOwner::Player(player);
+        self.velocity_x = -self.velocity_x;
+        self.deflected = true;
+    }
+
     pub fn is_out_of_bounds(&self, min_x: u16, max_x: u16, max_y: u16) -> bool {
         // Check if lifetime expired
         if let Some(lifetime) = self.lifetime
@@ -135,22 +464,44 @@ impl Projectile {
     }
 }
 
+/// Offsets of the full sword arc relative to its anchor, ordered left to right
+fn slash_arc_offsets(radius: u16) -> Vec<(i16, i16)> {
+    let r = radius as f32;
+    let steps = (radius * 8).max(1);
+    let mut offsets: Vec<(i16, i16)> = Vec::new();
+
+    for i in 0..=steps {
+        let angle = std::f32::consts::PI * (1.0 - i as f32 / steps as f32);
+        // Terminal cells are about twice as tall as they are wide, so stretch horizontally
+        let offset = (
+            (2.0 * r * angle.cos()).round() as i16,
+            -(r * angle.sin()).round() as i16,
+        );
+        if offsets.last() != Some(&offset) {
+            offsets.push(offset);
+        }
+    }
+
+    offsets
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::entities::EnemyType;
 
     #[test]
     fn test_projectile_new() {
-        let projectile = Projectile::new(10, 10, ProjectileOwner::Player);
+        let projectile = Projectile::new(10, 10, ProjectileOwner::Player(0));
         assert_eq!(projectile.x, 10);
         assert_eq!(projectile.y, 10);
-        assert_eq!(projectile.owner, ProjectileOwner::Player);
+        assert_eq!(projectile.owner, ProjectileOwner::Player(0));
         assert_eq!(projectile.damage, 10);
     }
 
     #[test]
     fn test_player_projectile_moves_up() {
-        let mut projectile = Projectile::new(10, 10, ProjectileOwner::Player);
+        let mut projectile = Projectile::new(10, 10, ProjectileOwner::Player(0));
         projectile.update();
         assert_eq!(projectile.y, 9);
     }
@@ -167,7 +518,7 @@ mod tests {
         let mut projectile = Projectile::new_with_type(
             10,
             10,
-            ProjectileOwner::Player,
+            ProjectileOwner::Player(0),
             ProjectileType::BugShot,
             2,
             None,
@@ -179,7 +530,7 @@ mod tests {
 
     #[test]
     fn test_projectile_out_of_bounds() {
-        let projectile = Projectile::new(0, 0, ProjectileOwner::Player);
+        let projectile = Projectile::new(0, 0, ProjectileOwner::Player(0));
         assert!(projectile.is_out_of_bounds(0, 80, 24));
 
         let projectile = Projectile::new(10, 24, ProjectileOwner::Enemy);
@@ -191,7 +542,7 @@ mod tests {
         let mut projectile = Projectile::new_with_type(
             10,
             10,
-            ProjectileOwner::Player,
+            ProjectileOwner::Player(0),
             ProjectileType::Slash,
             0,
             Some(3),
@@ -207,6 +558,219 @@ mod tests {
         assert!(projectile.is_out_of_bounds(0, 80, 24));
     }
 
+    #[test]
+    fn test_bomber_projectile_explodes_when_lifetime_expires() {
+        let mut projectile = Projectile::new_with_type(
+            10,
+            10,
+            ProjectileOwner::Player(0),
+            ProjectileType::BomberProjectile,
+            0,
+            Some(1),
+        );
+        assert!(!projectile.is_exploding());
+        projectile.update();
+        assert!(projectile.is_exploding());
+    }
+
+    #[test]
+    fn test_slash_stays_anchored() {
+        let mut slash = Projectile::new_slash(20, 10, SLASH_RADIUS, 0);
+        slash.update();
+        assert_eq!((slash.x, slash.y), (20, 10));
+        assert_eq!(slash.lifetime, Some(SLASH_LIFETIME - 1));
+
+        slash.follow(25, 8);
+        assert_eq!((slash.x, slash.y), (25, 8));
+    }
+
+    #[test]
+    fn test_slash_sweeps_across_arc() {
+        let mut slash = Projectile::new_slash(20, 10, SLASH_RADIUS, 0);
+        let first = slash.hit_cells();
+        assert!(first.len() > 1);
+        // Sweep starts on the left of the anchor
+        assert!(first.iter().all(|&(x, _)| x < 20));
+
+        while slash.lifetime > Some(1) {
+            slash.update();
+        }
+        let last = slash.hit_cells();
+        // ...and ends on the right
+        assert!(last.iter().all(|&(x, _)| x > 20));
+        // Arc never reaches below the anchor or beyond its radius
+        for (x, y) in first.iter().chain(last.iter()) {
+            assert!(*y <= 10 && *y >= 10 - SLASH_RADIUS);
+            assert!(x.abs_diff(20) <= SLASH_RADIUS * 2);
+        }
+    }
+
+    #[test]
+    fn test_slash_overlaps_rect() {
+        let slash = Projectile::new_slash(20, 10, SLASH_RADIUS, 0);
+        // Leftmost end of the arc sits level with the anchor
+        assert!(slash.overlaps_rect(6, 8, 4, 4));
+        assert!(!slash.overlaps_rect(30, 0, 4, 4));
+    }
+
+    #[test]
+    fn test_bomb_spawns_bomblets() {
+        let bomb = Projectile::new_bomb(20, 10, 90, BOMB_RADIUS, 4, 1);
+        let bomblets = bomb.spawn_bomblets();
+        assert_eq!(bomblets.len(), 4);
+
+        let velocities: Vec<i16> = bomblets.iter().map(|b| b.velocity_x).collect();
+        assert_eq!(velocities, vec![-1, 1, -2, 2]);
+        for bomblet in &bomblets {
+            assert_eq!(bomblet.projectile_type, ProjectileType::BomberProjectile);
+            assert_eq!(bomblet.radius, BOMB_RADIUS / 2);
+            // Bomblets never cluster again
+            assert_eq!(bomblet.bomblets, 0);
+            // Kills from the scatter still go to the bomb's owner
+            assert_eq!(bomblet.owner, ProjectileOwner::Player(1));
+        }
+
+        let plain_bomb = Projectile::new_bomb(20, 10, 90, BOMB_RADIUS, 0, 0);
+        assert!(plain_bomb.spawn_bomblets().is_empty());
+    }
+
+    #[test]
+    fn test_missile_acquires_nearest_enemy() {
+        let mut near = Enemy::new_in_formation(20, 10, EnemyType::Basic, 0, (0, 0));
+        near.id = 1;
+        let mut far = Enemy::new_in_formation(60, 0, EnemyType::Basic, 0, (0, 0));
+        far.id = 2;
+
+        let mut missile = Projectile::new_missile(20, 20, 0, 0);
+        missile.update_with_targets(&[far.clone(), near.clone()]);
+        assert_eq!(missile.target_id, Some(1));
+
+        // Retargets once its target dies
+        near.health = 0;
+        missile.update_with_targets(&[far, near]);
+        assert_eq!(missile.target_id, Some(2));
+    }
+
+    #[test]
+    fn test_missile_turns_toward_target_at_limited_rate() {
+        // Target directly to the right of the missile
+        let mut enemy = Enemy::new_in_formation(40, 20, EnemyType::Basic, 0, (0, 0));
+        enemy.id = 7;
+        let mut missile = Projectile::new_missile(10, 21, 0, 0);
+
+        let mut headings = Vec::new();
+        for _ in 0..(MISSILE_TURN_INTERVAL * 3) {
+            missile.update_with_targets(std::slice::from_ref(&enemy));
+            headings.push(missile.heading);
+        }
+
+        // Turns at most one 45 degree step per interval, ending up heading right
+        for pair in headings.windows(2) {
+            assert!(pair[0].abs_diff(pair[1]) <= 1);
+        }
+        assert_eq!(missile.heading, 2);
+    }
+
+    #[test]
+    fn test_missile_flies_along_heading_and_burns_out() {
+        let mut missile = Projectile::new_missile(10, 10, 2, 0);
+        missile.update_with_targets(&[]);
+        assert_eq!((missile.x, missile.y), (11, 10));
+
+        for _ in 0..MISSILE_LIFETIME {
+            missile.update_with_targets(&[]);
+        }
+        assert!(missile.is_out_of_bounds(0, 200, 200));
+    }
+
+    #[test]
+    fn test_projectiles_intersect_on_same_cell() {
+        let player_proj = Projectile::new(10, 10, ProjectileOwner::Player(0));
+        let enemy_proj = Projectile::new(10, 10, ProjectileOwner::Enemy);
+        assert!(player_proj.intersects(&enemy_proj));
+
+        let far_proj = Projectile::new(12, 10, ProjectileOwner::Enemy);
+        assert!(!player_proj.intersects(&far_proj));
+    }
+
+    #[test]
+    fn test_projectiles_intersect_after_crossing() {
+        // Player shot at y=10 and enemy shot at y=9 swap rows after one update
+        let mut player_proj = Projectile::new(10, 10, ProjectileOwner::Player(0));
+        let mut enemy_proj = Projectile::new(10, 9, ProjectileOwner::Enemy);
+        player_proj.update();
+        enemy_proj.update();
+        assert_eq!(player_proj.y, 9);
+        assert_eq!(enemy_proj.y, 10);
+        assert!(player_proj.intersects(&enemy_proj));
+        assert!(enemy_proj.intersects(&player_proj));
+    }
+
+    #[test]
+    fn test_projectile_interactions() {
+        let enemy_proj = Projectile::new(10, 10, ProjectileOwner::Enemy);
+
+        let bullet = Projectile::new(10, 10, ProjectileOwner::Player(0));
+        assert_eq!(
+            bullet.interaction_with(&enemy_proj, SHOOTABLE_ENEMY_PROJECTILES),
+            ProjectileInteraction::Cancel
+        );
+        assert_eq!(
+            bullet.interaction_with(&enemy_proj, &[]),
+            ProjectileInteraction::None
+        );
+
+        let slash = Projectile::new_with_type(
+            10,
+            10,
+            ProjectileOwner::Player(0),
+            ProjectileType::Slash,
+            0,
+            Some(10),
+        );
+        assert_eq!(
+            slash.interaction_with(&enemy_proj, &[]),
+            ProjectileInteraction::Deflect
+        );
+
+        let bomb = Projectile::new_with_type(
+            10,
+            10,
+            ProjectileOwner::Player(0),
+            ProjectileType::BomberProjectile,
+            0,
+            Some(90),
+        );
+        assert_eq!(
+            bomb.interaction_with(&enemy_proj, SHOOTABLE_ENEMY_PROJECTILES),
+            ProjectileInteraction::None
+        );
+
+        // Enemy projectiles never cancel each other
+        assert_eq!(
+            enemy_proj.interaction_with(&enemy_proj, SHOOTABLE_ENEMY_PROJECTILES),
+            ProjectileInteraction::None
+        );
+    }
+
+    #[test]
+    fn test_projectile_deflect() {
+        let mut projectile = Projectile::new_with_type(
+            10,
+            10,
+            ProjectileOwner::Enemy,
+            ProjectileType::Bullet,
+            1,
+            None,
+        );
+        projectile.deflect(1);
+        assert_eq!(projectile.owner, ProjectileOwner::Player(1));
+        assert_eq!(projectile.velocity_x, -1);
+
+        projectile.update();
+        assert_eq!(projectile.y, 9);
+    }
+
     // Property-based tests
     #[cfg(test)]
     mod proptests {
@@ -218,13 +782,13 @@ mod tests {
             fn test_projectile_moves_in_correct_direction(
                 initial_x in 5u16..75,
                 initial_y in 5u16..19,
-                owner in prop::sample::select(vec![ProjectileOwner::Player, ProjectileOwner::Enemy])
+                owner in prop::sample::select(vec![ProjectileOwner::Player(0), ProjectileOwner::Enemy])
             ) {
                 let mut projectile = Projectile::new(initial_x, initial_y, owner);
                 projectile.update();
 
                 match owner {
-                    ProjectileOwner::Player => {
+                    ProjectileOwner::Player(_) => {
                         // Player projectiles move up (y decreases)
                         prop_assert!(projectile.y < initial_y || initial_y == 0);
                     }
@@ -0,0 +1,147 @@
+/// Frames a chain stays alive after the last kill (2 seconds at 60 FPS)
+pub const CHAIN_WINDOW_FRAMES: u16 = 120;
+
+/// Chained kills needed to raise the multiplier by one
+pub const KILLS_PER_MULTIPLIER: u32 = 5;
+
+/// Highest score multiplier a chain can reach
+pub const MAX_MULTIPLIER: u32 = 8;
+
+/// Bonus for destroying every enemy in a formation, before the multiplier
+pub const FORMATION_BONUS: u32 = 500;
+
+/// Enemies shot down mid-dive are worth this many times their base points
+pub const DIVE_KILL_FACTOR: u32 = 2;
+
+/// Tracks consecutive kills and the score multiplier they build up
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScoreChain {
+    /// Kills in the current chain
+    pub chain: u32,
+    /// Longest chain reached this run
+    pub max_chain: u32,
+    /// Frames left before the chain drops
+    pub timer: u16,
+}
+
+impl ScoreChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current score multiplier, growing with every KILLS_PER_MULTIPLIER chained kills
+    pub fn get_multiplier(&self) -> u32 {
+        (1 + self.chain / KILLS_PER_MULTIPLIER).min(MAX_MULTIPLIER)
+    }
+
+    /// Extends the chain with a kill and returns the points it's worth
+    pub fn register_kill(&mut self, points: u32, diving: bool) -> u32 {
+        self.chain += 1;
+        self.max_chain = self.max_chain.max(self.chain);
+        self.timer = CHAIN_WINDOW_FRAMES;
+
+        let points = if diving {
+            points * DIVE_KILL_FACTOR
+        } else {
+            points
+        };
+        points * self.get_multiplier()
+    }
+
+    /// Points for clearing a whole formation at the current multiplier
+    pub fn formation_bonus(&self) -> u32 {
+        FORMATION_BONUS * self.get_multiplier()
+    }
+
+    /// Counts down the chain window, dropping the chain when it runs out
+    pub fn update(&mut self) {
+        if self.timer > 0 {
+            self.timer -= 1;
+            if self.timer == 0 {
+                self.chain = 0;
+            }
+        }
+    }
+
+    /// Drops the chain immediately (e.g. when the player takes damage)
+    pub fn reset(&mut self) {
+        self.chain = 0;
+        self.timer = 0;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.chain > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_builds_multiplier() {
+        let mut chain = ScoreChain::new();
+        assert_eq!(chain.get_multiplier(), 1);
+
+        for _ in 0..KILLS_PER_MULTIPLIER - 1 {
+            assert_eq!(chain.register_kill(10, false), 10);
+        }
+        // The kill that completes a step is already multiplied
+        assert_eq!(chain.register_kill(10, false), 20);
+        assert_eq!(chain.get_multiplier(), 2);
+        assert_eq!(chain.max_chain, KILLS_PER_MULTIPLIER);
+    }
+
+    #[test]
+    fn test_chain_multiplier_is_capped() {
+        let mut chain = ScoreChain::new();
+        for _ in 0..KILLS_PER_MULTIPLIER * (MAX_MULTIPLIER + 3) {
+            chain.register_kill(10, false);
+        }
+        assert_eq!(chain.get_multiplier(), MAX_MULTIPLIER);
+    }
+
+    #[test]
+    fn test_chain_expires_after_window() {
+        let mut chain = ScoreChain::new();
+        chain.register_kill(10, false);
+        for _ in 0..CHAIN_WINDOW_FRAMES - 1 {
+            chain.update();
+        }
+        assert!(chain.is_active());
+        chain.update();
+        assert!(!chain.is_active());
+        assert_eq!(chain.max_chain, 1);
+    }
+
+    #[test]
+    fn test_chain_kill_refreshes_window() {
+        let mut chain = ScoreChain::new();
+        chain.register_kill(10, false);
+        for _ in 0..CHAIN_WINDOW_FRAMES - 1 {
+            chain.update();
+        }
+        chain.register_kill(10, false);
+        assert_eq!(chain.timer, CHAIN_WINDOW_FRAMES);
+        assert_eq!(chain.chain, 2);
+    }
+
+    #[test]
+    fn test_chain_reset_keeps_max() {
+        let mut chain = ScoreChain::new();
+        for _ in 0..3 {
+            chain.register_kill(10, false);
+        }
+        chain.reset();
+        assert_eq!(chain.chain, 0);
+        assert_eq!(chain.max_chain, 3);
+        assert_eq!(chain.get_multiplier(), 1);
+    }
+
+    #[test]
+    fn test_dive_kills_and_formation_bonus() {
+        let mut chain = ScoreChain::new();
+        assert_eq!(chain.register_kill(10, true), 10 * DIVE_KILL_FACTOR);
+        assert_eq!(chain.formation_bonus(), FORMATION_BONUS);
+    }
+}
@@ -0,0 +1,151 @@
+use super::movement::{Handling, SUBCELLS_PER_CELL};
+use super::player::WeaponType;
+
+/// Passive bonus unique to each hull
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShipPassive {
+    /// Repair pickups restore twice as much health
+    Salvage,
+    /// Weapon cooldowns are a third shorter
+    RapidFire,
+    /// Incoming damage is halved
+    Armored,
+    /// Starts with and carries extra smart bombs
+    BombBay,
+}
+
+impl ShipPassive {
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            ShipPassive::Salvage => "Salvage: repairs restore double health",
+            ShipPassive::RapidFire => "Rapid fire: shorter weapon cooldowns",
+            ShipPassive::Armored => "Armored: takes half damage",
+            ShipPassive::BombBay => "Bomb bay: +2 bomb capacity, +1 starting bomb",
+        }
+    }
+}
+
+/// Static description of a selectable hull
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShipDefinition {
+    pub name: &'static str,
+    pub max_health: u8,
+    pub handling: Handling,
+    /// Hitbox and sprite size in cells
+    pub width: u16,
+    pub height: u16,
+    pub starting_weapon: WeaponType,
+    pub passive: ShipPassive,
+    /// Sprite image drawn when the terminal supports graphics
+    pub sprite_path: &'static str,
+    /// Sprite drawn in ASCII mode
+    pub ascii_sprite: &'static [&'static str],
+}
+
+const CELL: i16 = SUBCELLS_PER_CELL as i16;
+
+/// Every hull offered on the ship selection screen, in display order
+/// Add an entry here (and its sprite) to make a new ship selectable
+pub const SHIPS: &[ShipDefinition] = &[
+    ShipDefinition {
+        name: "Falcon",
+        max_health: 100,
+        handling: Handling {
+            max_speed: CELL,
+            acceleration: 2,
+            drag: 1,
+        },
+        width: 15,
+        height: 10,
+        starting_weapon: WeaponType::BasicGun,
+        passive: ShipPassive::Salvage,
+        sprite_path: "assests/sprites/ship.png",
+        ascii_sprite: &[" /^\\ ", "<|||>", " ||| "],
+    },
+    ShipDefinition {
+        name: "Wasp",
+        max_health: 70,
+        handling: Handling {
+            max_speed: CELL + CELL / 4,
+            acceleration: 3,
+            drag: 2,
+        },
+        width: 11,
+        height: 7,
+        starting_weapon: WeaponType::Bug,
+        passive: ShipPassive::RapidFire,
+        sprite_path: "assests/sprites/wasp.png",
+        ascii_sprite: &["\\^/", "<o>", "/ \\"],
+    },
+    ShipDefinition {
+        name: "Bulwark",
+        max_health: 150,
+        handling: Handling {
+            max_speed: CELL * 3 / 4,
+            acceleration: 1,
+            drag: 1,
+        },
+        width: 17,
+        height: 11,
+        starting_weapon: WeaponType::Bomber,
+        passive: ShipPassive::Armored,
+        sprite_path: "assests/sprites/bulwark.png",
+        ascii_sprite: &[" _/^\\_ ", "[|###|]", " \\===/ "],
+    },
+    ShipDefinition {
+        name: "Raven",
+        max_health: 90,
+        handling: Handling {
+            max_speed: CELL,
+            acceleration: 2,
+            drag: 1,
+        },
+        width: 15,
+        height: 10,
+        starting_weapon: WeaponType::Homing,
+        passive: ShipPassive::BombBay,
+        sprite_path: "assests/sprites/raven.png",
+        ascii_sprite: &[" /V\\ ", "<[=]>", " / \\ "],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ships_are_distinct() {
+        assert!(SHIPS.len() >= 2);
+        for (i, a) in SHIPS.iter().enumerate() {
+            for b in &SHIPS[i + 1..] {
+                assert_ne!(a.name, b.name);
+                assert_ne!(a.sprite_path, b.sprite_path);
+                assert_ne!(a.passive, b.passive);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ship_ascii_sprites_are_rectangular() {
+        for ship in SHIPS {
+            let width = ship.ascii_sprite[0].chars().count();
+            assert!(
+                ship.ascii_sprite
+                    .iter()
+                    .all(|line| line.chars().count() == width)
+            );
+            assert!(width as u16 <= ship.width);
+            assert!(ship.ascii_sprite.len() as u16 <= ship.height);
+        }
+    }
+
+    #[test]
+    fn test_ship_handling_is_usable() {
+        for ship in SHIPS {
+            assert!(ship.max_health > 0);
+            assert!(ship.handling.max_speed > 0);
+            assert!(ship.handling.acceleration > 0);
+            assert!(ship.handling.drag > 0);
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::storage;
+
+/// Number of entries kept in the table
+pub const MAX_HIGH_SCORES: usize = 10;
+
+/// Letters in an arcade-style initials entry
+pub const INITIALS_LEN: usize = 3;
+
+/// One finished run in the high-score table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HighScoreEntry {
+    pub initials: String,
+    pub score: u32,
+    /// Unix timestamp (seconds) of when the run ended
+    pub timestamp: u64,
+    pub time_survived_secs: u64,
+    /// Seed of the run's random number generator
+    pub seed: u64,
+    /// Game version the run was played on
+    pub version: String,
+}
+
+/// Top scores, highest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HighScoreTable {
+    pub entries: Vec<HighScoreEntry>,
+}
+
+impl HighScoreTable {
+    /// Where the table lives: `<data dir>/galagia/high_scores.json`
+    pub fn default_path() -> Option<PathBuf> {
+        storage::data_dir().map(|dir| dir.join("high_scores.json"))
+    }
+
+    /// Loads the table, falling back to an empty one if the file is missing or unreadable
+    /// A corrupt file is moved aside to `<name>.corrupt` so the next save doesn't lose it
+    pub fn load(path: &Path) -> Self {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+
+        match serde_json::from_str::<Self>(&contents) {
+            Ok(table) => table.normalized(),
+            Err(_) => {
+                let _ = fs::rename(path, path.with_extension("json.corrupt"));
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the table atomically so a crash mid-save keeps the old table
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        storage::write_json_atomic(path, self)
+    }
+
+    /// True if the score would earn a place in the table
+    pub fn qualifies(&self, score: u32) -> bool {
+        score > 0
+            && (self.entries.len() < MAX_HIGH_SCORES
+                || self.entries.last().is_some_and(|last| score > last.score))
+    }
+
+    /// Adds an entry below any equal scores, returning its rank (0 = top)
+    /// Returns None if it didn't make the table
+    pub fn insert(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        let rank = self
+            .entries
+            .iter()
+            .position(|e| entry.score > e.score)
+            .unwrap_or(self.entries.len());
+        if rank >= MAX_HIGH_SCORES {
+            return None;
+        }
+
+        self.entries.insert(rank, entry);
+        self.entries.truncate(MAX_HIGH_SCORES);
+        Some(rank)
+    }
+
+    /// Sorts, trims and sanitizes a table read from disk
+    fn normalized(mut self) -> Self {
+        self.entries.sort_by_key(|e| Reverse(e.score));
+        self.entries.truncate(MAX_HIGH_SCORES);
+        for entry in &mut self.entries {
+            entry.initials = entry
+                .initials
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .take(INITIALS_LEN)
+                .collect::<String>()
+                .to_ascii_uppercase();
+        }
+        self
+    }
+}
+
+/// Arcade-style initials entry: cycle letters up and down, move between slots
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InitialsEntry {
+    pub letters: [char; INITIALS_LEN],
+    /// Slot currently being edited
+    pub cursor: usize,
+}
+
+impl Default for InitialsEntry {
+    fn default() -> Self {
+        Self {
+            letters: ['A'; INITIALS_LEN],
+            cursor: 0,
+        }
+    }
+}
+
+impl InitialsEntry {
+    const ALPHABET: &'static [u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+    fn shift_letter(&mut self, step: isize) {
+        let current = self.letters[self.cursor] as u8;
+        let index = Self::ALPHABET
+            .iter()
+            .position(|&c| c == current)
+            .unwrap_or(0) as isize;
+        let len = Self::ALPHABET.len() as isize;
+        self.letters[self.cursor] = Self::ALPHABET[(index + step).rem_euclid(len) as usize] as char;
+    }
+
+    pub fn next_letter(&mut self) {
+        self.shift_letter(1);
+    }
+
+    pub fn previous_letter(&mut self) {
+        self.shift_letter(-1);
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(INITIALS_LEN - 1);
+    }
+
+    /// Sets the current slot to a typed letter or digit and advances
+    pub fn type_char(&mut self, c: char) {
+        if !c.is_ascii_alphanumeric() {
+            return;
+        }
+        self.letters[self.cursor] = c.to_ascii_uppercase();
+        self.move_right();
+    }
+
+    pub fn get_initials(&self) -> String {
+        self.letters.iter().collect()
+    }
+}
+
+/// Formats a Unix timestamp as a UTC `YYYY-MM-DD` date
+pub fn format_date(timestamp: u64) -> String {
+    // Civil-from-days conversion (Howard Hinnant's algorithm)
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(initials: &str, score: u32) -> HighScoreEntry {
+        HighScoreEntry {
+            initials: initials.to_string(),
+            score,
+            timestamp: 0,
+            time_survived_secs: 60,
+            seed: 42,
+            version: "0.1.0".to_string(),
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("galagia-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("high_scores.json")
+    }
+
+    #[test]
+    fn test_insert_keeps_table_sorted_and_bounded() {
+        let mut table = HighScoreTable::default();
+        for i in 0..MAX_HIGH_SCORES as u32 + 5 {
+            table.insert(entry("AAA", i * 10));
+        }
+        assert_eq!(table.entries.len(), MAX_HIGH_SCORES);
+        assert!(table.entries.windows(2).all(|w| w[0].score >= w[1].score));
+        assert_eq!(table.entries[0].score, (MAX_HIGH_SCORES as u32 + 4) * 10);
+    }
+
+    #[test]
+    fn test_insert_ties_rank_below_existing() {
+        let mut table = HighScoreTable::default();
+        table.insert(entry("OLD", 100));
+        assert_eq!(table.insert(entry("NEW", 100)), Some(1));
+        assert_eq!(table.entries[0].initials, "OLD");
+    }
+
+    #[test]
+    fn test_qualifies() {
+        let mut table = HighScoreTable::default();
+        assert!(!table.qualifies(0));
+        assert!(table.qualifies(10));
+        for _ in 0..MAX_HIGH_SCORES {
+            table.insert(entry("AAA", 100));
+        }
+        assert!(!table.qualifies(100));
+        assert!(table.qualifies(101));
+        assert_eq!(table.insert(entry("LOW", 50)), None);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = temp_path("round-trip");
+        let mut table = HighScoreTable::default();
+        table.insert(entry("ABC", 300));
+        table.insert(entry("XYZ", 100));
+        table.save(&path).unwrap();
+
+        assert_eq!(HighScoreTable::load(&path), table);
+        assert!(!path.with_extension("json.tmp").exists());
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let path = temp_path("missing");
+        assert_eq!(HighScoreTable::load(&path), HighScoreTable::default());
+    }
+
+    #[test]
+    fn test_load_corrupt_file_is_empty_and_moved_aside() {
+        let path = temp_path("corrupt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{ not json").unwrap();
+
+        assert_eq!(HighScoreTable::load(&path), HighScoreTable::default());
+        assert!(!path.exists());
+        assert!(path.with_extension("json.corrupt").exists());
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_load_sanitizes_entries() {
+        let path = temp_path("sanitize");
+        let mut table = HighScoreTable::default();
+        table.entries.push(entry("lo", 10));
+        table.entries.push(entry("h!ighest", 500));
+        table.save(&path).unwrap();
+
+        let loaded = HighScoreTable::load(&path);
+        assert_eq!(loaded.entries[0].initials, "HIG");
+        assert_eq!(loaded.entries[1].initials, "LO");
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_initials_entry() {
+        let mut initials = InitialsEntry::default();
+        initials.previous_letter();
+        assert_eq!(initials.letters[0], '9');
+        initials.next_letter();
+        initials.next_letter();
+        assert_eq!(initials.letters[0], 'B');
+
+        initials.type_char('k');
+        assert_eq!(initials.cursor, 1);
+        initials.type_char('-');
+        initials.type_char('7');
+        initials.type_char('Z');
+        // Typing past the end keeps overwriting the last slot
+        assert_eq!(initials.get_initials(), "K7Z");
+        initials.move_left();
+        initials.move_left();
+        initials.move_left();
+        assert_eq!(initials.cursor, 0);
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_767_225_600), "2026-01-01");
+    }
+}
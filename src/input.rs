@@ -1,7 +1,14 @@
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use std::time::Duration;
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant};
 
+use crate::bindings::{Control, KeyBinding, KeyBindings};
 use crate::entities::GameState;
+use crate::input_source::{CrosstermSource, InputSource};
 
 /// Represents semantic game actions that can be triggered by input
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -11,26 +18,94 @@ pub enum InputAction {
     MoveUp,
     MoveDown,
     Fire,
+    Focus,
+    /// Player two's controls in co-op
+    P2MoveLeft,
+    P2MoveRight,
+    P2MoveUp,
+    P2MoveDown,
+    P2Fire,
+    P2Focus,
+    P2Dash,
+    P2Bomb,
+    Bomb,
+    Dash,
+    ToggleMovement,
+    ToggleFloatingText,
+    MenuUp,
+    MenuDown,
+    MenuLeft,
+    MenuRight,
+    Confirm,
+    /// Close the current screen and return to the one beneath it
+    Back,
+    /// A letter or digit typed into a text entry
+    TypeChar(char),
+    /// Key pressed while the controls screen was waiting for a new binding
+    BindKey(KeyBinding),
     Pause,
     Resume,
     Restart,
     Quit,
 }
 
+/// How long a key counts as held after its last press, for terminals that never report
+/// key releases. Holding a key there shows up as a press, a pause, then a stream of repeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyRepeatTimeouts {
+    /// Milliseconds after the first press; must outlast the terminal's delay before repeating
+    pub initial_ms: u64,
+    /// Milliseconds after each repeat; a little longer than the gap between repeats
+    pub repeat_ms: u64,
+}
+
+impl Default for KeyRepeatTimeouts {
+    fn default() -> Self {
+        Self {
+            initial_ms: 500,
+            repeat_ms: 100,
+        }
+    }
+}
+
+/// When a held control was last pressed, for the key repeat fallback
+#[derive(Debug, Clone, Copy)]
+struct HeldKey {
+    last_press: Instant,
+    /// At least one repeat has arrived since the first press
+    repeating: bool,
+}
+
 /// Tracks the state of keys that can be held down for continuous input
 #[derive(Debug, Default)]
 struct KeyState {
-    up: bool,
-    down: bool,
-    left: bool,
-    right: bool,
-    fire: bool,
+    /// Held controls, each repeating its action every frame
+    held: BTreeSet<Control>,
+    /// Left mouse button, fires alongside the fire key
+    mouse_fire: bool,
 }
 
 /// Manages input polling and translates raw key events into game actions
 pub struct InputManager {
+    /// Where events are read from, the terminal unless replaced
+    source: Box<dyn InputSource>,
     key_state: KeyState,
     oneshot_actions: Vec<InputAction>,
+    bindings: KeyBindings,
+    /// Player two's keys are read, ahead of player one's, during a co-op run
+    co_op: bool,
+    /// The next key press is reported as a BindKey action instead of being handled
+    capturing: bool,
+    /// Mouse events are ignored unless mouse control is on
+    mouse_enabled: bool,
+    /// Last known cursor cell in terminal coordinates
+    mouse_position: Option<(u16, u16)>,
+    /// Set when the terminal can't report key releases, so held keys expire instead
+    repeat_fallback: Option<KeyRepeatTimeouts>,
+    held_keys: BTreeMap<Control, HeldKey>,
+    /// Time of the poll being handled
+    now: Instant,
 }
 
 impl Default for InputManager {
@@ -40,28 +115,92 @@ impl Default for InputManager {
 }
 
 impl InputManager {
-    /// Creates a new InputManager with default key state
+    /// Creates a new InputManager reading from the terminal, with default key state
     pub fn new() -> Self {
+        Self::with_source(CrosstermSource)
+    }
+
+    /// Creates a new InputManager reading from another event source, e.g. a script
+    pub fn with_source(source: impl InputSource + 'static) -> Self {
         Self {
+            source: Box::new(source),
             key_state: KeyState::default(),
             oneshot_actions: Vec::new(),
+            bindings: KeyBindings::default(),
+            co_op: false,
+            capturing: false,
+            mouse_enabled: false,
+            mouse_position: None,
+            repeat_fallback: None,
+            held_keys: BTreeMap::new(),
+            now: Instant::now(),
         }
     }
 
+    /// Treats keys as held only while presses keep arriving within the given timeouts, for
+    /// terminals without key release events; None goes back to waiting for releases
+    pub fn set_key_repeat_fallback(&mut self, timeouts: Option<KeyRepeatTimeouts>) {
+        self.repeat_fallback = timeouts;
+        self.held_keys.clear();
+    }
+
+    /// Turns mouse control on or off, forgetting the cursor and buttons when turned off
+    pub fn set_mouse_enabled(&mut self, enabled: bool) {
+        self.mouse_enabled = enabled;
+        if !enabled {
+            self.mouse_position = None;
+            self.key_state.mouse_fire = false;
+        }
+    }
+
+    /// Cell the mouse cursor was last seen over, if mouse control is on
+    pub fn get_mouse_position(&self) -> Option<(u16, u16)> {
+        self.mouse_position
+    }
+
+    /// Replaces the key bindings, e.g. after they're changed on the controls screen
+    pub fn set_bindings(&mut self, bindings: KeyBindings) {
+        self.bindings = bindings;
+    }
+
+    /// Switches between one and two players, letting go of every held key
+    pub fn set_co_op(&mut self, co_op: bool) {
+        self.co_op = co_op;
+        self.key_state.held.clear();
+        self.held_keys.clear();
+    }
+
+    /// Captures the next key press for rebinding; Esc cancels with a Back action
+    pub fn start_capture(&mut self) {
+        self.capturing = true;
+    }
+
     /// Polls for all input events and stores one-shot actions
     /// Should be called once per frame before getting actions
     pub fn poll_events(&mut self, game_state: &GameState) -> color_eyre::Result<()> {
+        self.poll_events_at(game_state, Instant::now())
+    }
+
+    /// Same as poll_events, with the current time given so held keys can be timed out
+    /// predictably
+    pub fn poll_events_at(
+        &mut self,
+        game_state: &GameState,
+        now: Instant,
+    ) -> color_eyre::Result<()> {
+        self.now = now;
+
         // Clear previous one-shot actions
         self.oneshot_actions.clear();
 
-        // Poll for all available events without blocking
-        while event::poll(Duration::from_millis(0))? {
-            match event::read()? {
+        // Take every waiting event without blocking
+        while let Some(event) = self.source.poll_event()? {
+            match event {
                 Event::Key(key_event) => {
                     self.handle_key_event(key_event, game_state);
                 }
-                Event::Mouse(_) => {
-                    // Mouse events currently ignored
+                Event::Mouse(mouse_event) if self.mouse_enabled => {
+                    self.handle_mouse_event(mouse_event, game_state);
                 }
                 Event::Resize(_, _) => {
                     // Resize events handled elsewhere
@@ -70,9 +209,35 @@ impl InputManager {
             }
         }
 
+        self.expire_held_keys();
         Ok(())
     }
 
+    /// Without release events, lets go of held controls whose presses have stopped arriving
+    fn expire_held_keys(&mut self) {
+        let Some(timeouts) = self.repeat_fallback else {
+            return;
+        };
+        let now = self.now;
+        let expired: Vec<Control> = self
+            .held_keys
+            .iter()
+            .filter(|(_, held)| {
+                let timeout = if held.repeating {
+                    timeouts.repeat_ms
+                } else {
+                    timeouts.initial_ms
+                };
+                now.duration_since(held.last_press) > Duration::from_millis(timeout)
+            })
+            .map(|(&control, _)| control)
+            .collect();
+        for control in expired {
+            self.held_keys.remove(&control);
+            self.release_control(control);
+        }
+    }
+
     /// Processes a key event and updates key state and one-shot actions
     fn handle_key_event(&mut self, key_event: KeyEvent, game_state: &GameState) {
         match key_event.kind {
@@ -88,90 +253,204 @@ impl InputManager {
 
     /// Handles key press events
     fn handle_key_press(&mut self, key_event: KeyEvent, game_state: &GameState) {
-        // Check for quit keys first (works in any state)
-        if matches!(
-            key_event.code,
-            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc
-        ) || (key_event.code == KeyCode::Char('c')
-            && key_event.modifiers.contains(KeyModifiers::CONTROL))
+        let ctrl_c = key_event.code == KeyCode::Char('c')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL);
+
+        // A key being bound is passed on as-is; keys with no name are ignored
+        if self.capturing && !ctrl_c {
+            let action = match key_event.code {
+                KeyCode::Esc => Some(InputAction::Back),
+                _ => KeyBinding::from_event(&key_event).map(InputAction::BindKey),
+            };
+            if let Some(action) = action {
+                self.capturing = false;
+                self.oneshot_actions.push(action);
+            }
+            return;
+        }
+
+        let control = KeyBinding::from_event(&key_event)
+            .and_then(|binding| self.bindings.get_control(binding, self.co_op));
+
+        // Check for quit keys first (works in any state, though the quit key is just a letter
+        // while typing, Esc steps back out of menus and Esc while typing keeps the score)
+        let typing = *game_state == GameState::EnterInitials;
+        if (!typing && control == Some(Control::Quit))
+            || (!typing && !game_state.is_menu() && key_event.code == KeyCode::Esc)
+            || ctrl_c
         {
             self.oneshot_actions.push(InputAction::Quit);
             return;
         }
 
+        // The pause key still toggles pause straight back to the game
+        if *game_state == GameState::Paused && control == Some(Control::Pause) {
+            self.oneshot_actions.push(InputAction::Resume);
+            return;
+        }
+
+        // Menu screens share one set of navigation keys
+        if game_state.is_menu() {
+            let action = match key_event.code {
+                KeyCode::Char('w') | KeyCode::Char('W') | KeyCode::Up => Some(InputAction::MenuUp),
+                KeyCode::Char('s') | KeyCode::Char('S') | KeyCode::Down => {
+                    Some(InputAction::MenuDown)
+                }
+                KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Left => {
+                    Some(InputAction::MenuLeft)
+                }
+                KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Right => {
+                    Some(InputAction::MenuRight)
+                }
+                KeyCode::Enter | KeyCode::Char(' ') => Some(InputAction::Confirm),
+                KeyCode::Esc | KeyCode::Backspace => Some(InputAction::Back),
+                _ => None,
+            };
+            if let Some(action) = action {
+                self.oneshot_actions.push(action);
+            }
+            return;
+        }
+
         // State-specific one-shot actions
         match game_state {
+            GameState::Title => {
+                // Any key leaves the title screen
+                self.oneshot_actions.push(InputAction::Confirm);
+                return;
+            }
+            GameState::MainMenu
+            | GameState::HighScores
+            | GameState::Settings
+            | GameState::ShipSelect
+            | GameState::Paused
+            | GameState::Controls
+            | GameState::Confirmation => {}
             GameState::Playing => {
-                if matches!(key_event.code, KeyCode::Char('p') | KeyCode::Char('P')) {
-                    self.oneshot_actions.push(InputAction::Pause);
+                if let Some(
+                    control @ (Control::Pause
+                    | Control::Bomb
+                    | Control::Dash
+                    | Control::P2Bomb
+                    | Control::P2Dash
+                    | Control::ToggleMovement
+                    | Control::ToggleFloatingText),
+                ) = control
+                {
+                    self.oneshot_actions.push(control.get_action());
                     return;
                 }
             }
-            GameState::Paused => {
-                if matches!(key_event.code, KeyCode::Char('p') | KeyCode::Char('P')) {
-                    self.oneshot_actions.push(InputAction::Resume);
-                    return;
+            GameState::EnterInitials => {
+                let action = match key_event.code {
+                    KeyCode::Up => Some(InputAction::MenuUp),
+                    KeyCode::Down => Some(InputAction::MenuDown),
+                    KeyCode::Left | KeyCode::Backspace => Some(InputAction::MenuLeft),
+                    KeyCode::Right => Some(InputAction::MenuRight),
+                    // Esc saves the initials as they stand rather than losing the score
+                    KeyCode::Enter | KeyCode::Esc => Some(InputAction::Confirm),
+                    KeyCode::Char(c) => Some(InputAction::TypeChar(c)),
+                    _ => None,
+                };
+                if let Some(action) = action {
+                    self.oneshot_actions.push(action);
                 }
+                return;
             }
             GameState::GameOver => {
-                if matches!(key_event.code, KeyCode::Char('r') | KeyCode::Char('R')) {
+                if control == Some(Control::Restart) {
                     self.oneshot_actions.push(InputAction::Restart);
                     return;
                 }
+                if key_event.code == KeyCode::Enter {
+                    self.oneshot_actions.push(InputAction::Confirm);
+                    return;
+                }
             }
         }
 
         // Continuous action keys (only tracked in Playing state)
         if *game_state == GameState::Playing {
-            match key_event.code {
-                // Movement keys - WASD
-                KeyCode::Char('w') | KeyCode::Char('W') | KeyCode::Up => {
-                    self.key_state.up = true;
-                    self.key_state.down = false;
-                }
-                KeyCode::Char('s') | KeyCode::Char('S') | KeyCode::Down => {
-                    self.key_state.down = true;
-                    self.key_state.up = false;
-                }
-                KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Left => {
-                    self.key_state.left = true;
-                    self.key_state.right = false;
-                }
-                KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Right => {
-                    self.key_state.right = true;
-                    self.key_state.left = false;
-                }
-                // Fire key
-                KeyCode::Char(' ') => {
-                    self.key_state.fire = true;
+            if let Some(control) = control
+                && control.is_held()
+                && self.repeat_fallback.is_some()
+            {
+                // A press while still held is the terminal repeating the key
+                let repeating = self.held_keys.contains_key(&control);
+                self.held_keys.insert(
+                    control,
+                    HeldKey {
+                        last_press: self.now,
+                        repeating,
+                    },
+                );
+            }
+
+            // Focus is Shift by default, which needs modifier key events from keyboard
+            // enhancement
+            if let Some(control) = control
+                && control.is_held()
+            {
+                // Pressing a direction cancels the opposite one
+                if let Some(opposite) = control.get_opposite() {
+                    self.key_state.held.remove(&opposite);
                 }
-                _ => {}
+                self.key_state.held.insert(control);
             }
         }
     }
 
-    /// Handles key release events
-    fn handle_key_release(&mut self, code: KeyCode) {
-        match code {
-            KeyCode::Char('w') | KeyCode::Char('W') | KeyCode::Up => {
-                self.key_state.up = false;
+    /// Tracks the cursor and turns clicks and scrolling into actions
+    /// In play the left button fires and the right drops a smart bomb; in menus the wheel
+    /// moves the highlight, left click chooses and right click goes back
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent, game_state: &GameState) {
+        self.mouse_position = Some((mouse_event.column, mouse_event.row));
+
+        let action = match (mouse_event.kind, game_state) {
+            (MouseEventKind::Down(MouseButton::Left), GameState::Playing) => {
+                self.key_state.mouse_fire = true;
+                None
             }
-            KeyCode::Char('s') | KeyCode::Char('S') | KeyCode::Down => {
-                self.key_state.down = false;
+            (MouseEventKind::Up(MouseButton::Left), _) => {
+                self.key_state.mouse_fire = false;
+                None
             }
-            KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Left => {
-                self.key_state.left = false;
+            (MouseEventKind::Down(MouseButton::Right), GameState::Playing) => {
+                Some(InputAction::Bomb)
             }
-            KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Right => {
-                self.key_state.right = false;
+            (MouseEventKind::Down(MouseButton::Left), GameState::Title | GameState::GameOver) => {
+                Some(InputAction::Confirm)
             }
-            KeyCode::Char(' ') => {
-                self.key_state.fire = false;
+            (MouseEventKind::Down(MouseButton::Left), state) if state.is_menu() => {
+                Some(InputAction::Confirm)
             }
-            _ => {}
+            (MouseEventKind::Down(MouseButton::Right), state) if state.is_menu() => {
+                Some(InputAction::Back)
+            }
+            // Ships are laid out side by side
+            (MouseEventKind::ScrollUp, GameState::ShipSelect) => Some(InputAction::MenuLeft),
+            (MouseEventKind::ScrollDown, GameState::ShipSelect) => Some(InputAction::MenuRight),
+            (MouseEventKind::ScrollUp, state) if state.is_menu() => Some(InputAction::MenuUp),
+            (MouseEventKind::ScrollDown, state) if state.is_menu() => Some(InputAction::MenuDown),
+            _ => None,
+        };
+        if let Some(action) = action {
+            self.oneshot_actions.push(action);
         }
     }
 
+    /// Handles key release events
+    fn handle_key_release(&mut self, code: KeyCode) {
+        if let Some(control) = self.bindings.get_control_for_code(code, self.co_op) {
+            self.release_control(control);
+        }
+    }
+
+    /// Stops a held control
+    fn release_control(&mut self, control: Control) {
+        self.key_state.held.remove(&control);
+    }
+
     /// Returns all actions for this frame (both continuous and one-shot)
     /// Must be called after poll_events()
     pub fn get_actions(&self, game_state: &GameState) -> Vec<InputAction> {
@@ -182,19 +461,8 @@ impl InputManager {
 
         // Add continuous actions based on held keys (only in Playing state)
         if *game_state == GameState::Playing {
-            if self.key_state.left {
-                actions.push(InputAction::MoveLeft);
-            }
-            if self.key_state.right {
-                actions.push(InputAction::MoveRight);
-            }
-            if self.key_state.up {
-                actions.push(InputAction::MoveUp);
-            }
-            if self.key_state.down {
-                actions.push(InputAction::MoveDown);
-            }
-            if self.key_state.fire {
+            actions.extend(self.key_state.held.iter().map(Control::get_action));
+            if self.key_state.mouse_fire && !self.key_state.held.contains(&Control::Fire) {
                 actions.push(InputAction::Fire);
             }
         }
@@ -202,3 +470,431 @@ impl InputManager {
         actions
     }
 }
+
+/// Direction from a ship to the terminal cell under the mouse cursor, still once within a
+/// cell of it. `ship` is in game coordinates, which start inside the left border when the
+/// play area is narrowed by `edge_width` columns on each side
+pub fn steer_toward((column, row): (u16, u16), ship: Rect, edge_width: u16) -> (i16, i16) {
+    let origin_x = if edge_width > 0 { edge_width + 1 } else { 0 };
+    let target_x = column as i32 - origin_x as i32;
+    let center_x = (ship.x + ship.width / 2) as i32;
+    let center_y = (ship.y + ship.height / 2) as i32;
+    let direction = |offset: i32| {
+        if offset.abs() <= 1 {
+            0
+        } else {
+            offset.signum() as i16
+        }
+    };
+    (
+        direction(target_x - center_x),
+        direction(row as i32 - center_y),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input_source::{ScriptedEvent, ScriptedSource};
+    use crossterm::event::ModifierKeyCode;
+
+    fn press(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn release(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new_with_kind(
+            code,
+            KeyModifiers::NONE,
+            KeyEventKind::Release,
+        ))
+    }
+
+    fn mouse(kind: MouseEventKind) -> Event {
+        Event::Mouse(MouseEvent {
+            kind,
+            column: 30,
+            row: 20,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    /// An input manager playing back a script
+    fn scripted(events: Vec<(u64, Event)>) -> InputManager {
+        let script = events
+            .into_iter()
+            .map(|(frame, event)| ScriptedEvent { frame, event })
+            .collect();
+        InputManager::with_source(ScriptedSource::new(script))
+    }
+
+    /// Polls an input manager for some frames, returning each frame's actions
+    fn run_frames(
+        input: &mut InputManager,
+        frames: u64,
+        state: GameState,
+    ) -> Vec<Vec<InputAction>> {
+        (0..frames)
+            .map(|_| {
+                input.poll_events(&state).unwrap();
+                input.get_actions(&state)
+            })
+            .collect()
+    }
+
+    /// Runs a script through an input manager, returning each frame's actions
+    fn run_script(
+        events: Vec<(u64, Event)>,
+        frames: u64,
+        state: GameState,
+    ) -> Vec<Vec<InputAction>> {
+        run_frames(&mut scripted(events), frames, state)
+    }
+
+    #[test]
+    fn test_held_keys_last_until_released() {
+        let frames = run_script(
+            vec![
+                (0, press(KeyCode::Char('d'))),
+                (0, press(KeyCode::Char(' '))),
+                (2, release(KeyCode::Char('d'))),
+            ],
+            3,
+            GameState::Playing,
+        );
+        assert_eq!(frames[0], vec![InputAction::MoveRight, InputAction::Fire]);
+        assert_eq!(frames[1], vec![InputAction::MoveRight, InputAction::Fire]);
+        assert_eq!(frames[2], vec![InputAction::Fire]);
+    }
+
+    #[test]
+    fn test_opposite_direction_cancels() {
+        let frames = run_script(
+            vec![
+                (0, press(KeyCode::Up)),
+                (1, press(KeyCode::Char('s'))),
+                // Releasing the cancelled key doesn't stop the newer one
+                (2, release(KeyCode::Up)),
+            ],
+            3,
+            GameState::Playing,
+        );
+        assert_eq!(frames[0], vec![InputAction::MoveUp]);
+        assert_eq!(frames[1], vec![InputAction::MoveDown]);
+        assert_eq!(frames[2], vec![InputAction::MoveDown]);
+    }
+
+    #[test]
+    fn test_one_shot_actions_last_a_single_frame() {
+        let frames = run_script(
+            vec![
+                (0, press(KeyCode::Char('B'))),
+                (1, press(KeyCode::Char('p'))),
+            ],
+            3,
+            GameState::Playing,
+        );
+        assert_eq!(frames[0], vec![InputAction::Bomb]);
+        assert_eq!(frames[1], vec![InputAction::Pause]);
+        assert!(frames[2].is_empty());
+    }
+
+    #[test]
+    fn test_keys_follow_bindings() {
+        let mut bindings = KeyBindings::default();
+        bindings
+            .bind(Control::Fire, KeyBinding::key(KeyCode::Char('j')))
+            .unwrap();
+        let mut input = InputManager::with_source(ScriptedSource::new(vec![ScriptedEvent {
+            frame: 0,
+            event: press(KeyCode::Char('j')),
+        }]));
+        input.set_bindings(bindings);
+
+        input.poll_events(&GameState::Playing).unwrap();
+        assert_eq!(
+            input.get_actions(&GameState::Playing),
+            vec![InputAction::Fire]
+        );
+    }
+
+    #[test]
+    fn test_capture_reports_the_next_key() {
+        let mut input = InputManager::with_source(ScriptedSource::new(vec![
+            ScriptedEvent {
+                frame: 0,
+                event: press(KeyCode::Char('q')),
+            },
+            ScriptedEvent {
+                frame: 1,
+                event: press(KeyCode::Char('q')),
+            },
+        ]));
+        input.start_capture();
+
+        // Captured as a binding instead of quitting
+        input.poll_events(&GameState::Controls).unwrap();
+        assert_eq!(
+            input.get_actions(&GameState::Controls),
+            vec![InputAction::BindKey(KeyBinding::key(KeyCode::Char('q')))]
+        );
+        input.poll_events(&GameState::Controls).unwrap();
+        assert_eq!(
+            input.get_actions(&GameState::Controls),
+            vec![InputAction::Quit]
+        );
+    }
+
+    #[test]
+    fn test_repeat_fallback_holds_keys_while_repeats_arrive() {
+        let script = [0, 1, 3, 4, 5]
+            .into_iter()
+            .map(|frame| ScriptedEvent {
+                frame,
+                event: press(KeyCode::Char('a')),
+            })
+            .collect();
+        let mut input = InputManager::with_source(ScriptedSource::new(script));
+        input.set_key_repeat_fallback(Some(KeyRepeatTimeouts {
+            initial_ms: 400,
+            repeat_ms: 50,
+        }));
+
+        // Polled at these times (ms): a press, the first repeat after the terminal's delay,
+        // a gap shorter than the repeat timeout, then repeats stop and the key times out
+        let start = Instant::now();
+        let held: Vec<bool> = [0, 350, 380, 390, 420, 425, 480, 700]
+            .into_iter()
+            .map(|ms| {
+                input
+                    .poll_events_at(&GameState::Playing, start + Duration::from_millis(ms))
+                    .unwrap();
+                input
+                    .get_actions(&GameState::Playing)
+                    .contains(&InputAction::MoveLeft)
+            })
+            .collect();
+        assert_eq!(held, vec![true, true, true, true, true, true, false, false]);
+    }
+
+    #[test]
+    fn test_single_press_expires_after_initial_timeout() {
+        let mut input = InputManager::with_source(ScriptedSource::new(vec![ScriptedEvent {
+            frame: 0,
+            event: press(KeyCode::Char(' ')),
+        }]));
+        input.set_key_repeat_fallback(Some(KeyRepeatTimeouts::default()));
+
+        let start = Instant::now();
+        input.poll_events_at(&GameState::Playing, start).unwrap();
+        assert_eq!(
+            input.get_actions(&GameState::Playing),
+            vec![InputAction::Fire]
+        );
+        input
+            .poll_events_at(&GameState::Playing, start + Duration::from_millis(499))
+            .unwrap();
+        assert_eq!(
+            input.get_actions(&GameState::Playing),
+            vec![InputAction::Fire]
+        );
+        input
+            .poll_events_at(&GameState::Playing, start + Duration::from_millis(501))
+            .unwrap();
+        assert!(input.get_actions(&GameState::Playing).is_empty());
+    }
+
+    #[test]
+    fn test_co_op_splits_the_keyboard() {
+        let mut input = InputManager::with_source(ScriptedSource::new(vec![
+            ScriptedEvent {
+                frame: 0,
+                event: press(KeyCode::Char('a')),
+            },
+            ScriptedEvent {
+                frame: 0,
+                event: press(KeyCode::Right),
+            },
+            ScriptedEvent {
+                frame: 0,
+                event: press(KeyCode::Enter),
+            },
+            ScriptedEvent {
+                frame: 1,
+                event: release(KeyCode::Right),
+            },
+        ]));
+        input.set_co_op(true);
+
+        input.poll_events(&GameState::Playing).unwrap();
+        assert_eq!(
+            input.get_actions(&GameState::Playing),
+            vec![
+                InputAction::MoveLeft,
+                InputAction::P2MoveRight,
+                InputAction::P2Fire
+            ]
+        );
+        input.poll_events(&GameState::Playing).unwrap();
+        assert_eq!(
+            input.get_actions(&GameState::Playing),
+            vec![InputAction::MoveLeft, InputAction::P2Fire]
+        );
+    }
+
+    #[test]
+    fn test_co_op_gives_player_two_focus_dash_and_bomb() {
+        let events = vec![
+            (0, press(KeyCode::Modifier(ModifierKeyCode::RightShift))),
+            (0, press(KeyCode::End)),
+            (1, press(KeyCode::PageDown)),
+            (1, press(KeyCode::Modifier(ModifierKeyCode::LeftShift))),
+            (2, release(KeyCode::Modifier(ModifierKeyCode::RightShift))),
+        ];
+        let mut input = scripted(events.clone());
+        input.set_co_op(true);
+        let frames = run_frames(&mut input, 3, GameState::Playing);
+        assert_eq!(frames[0], vec![InputAction::P2Dash, InputAction::P2Focus]);
+        assert_eq!(
+            frames[1],
+            vec![
+                InputAction::P2Bomb,
+                InputAction::Focus,
+                InputAction::P2Focus
+            ]
+        );
+        assert_eq!(frames[2], vec![InputAction::Focus]);
+
+        // Alone, Right Shift is player one's focus and the other keys do nothing
+        let frames = run_script(events, 1, GameState::Playing);
+        assert_eq!(frames[0], vec![InputAction::Focus]);
+    }
+
+    #[test]
+    fn test_esc_on_initials_entry_saves_instead_of_quitting() {
+        let frames = run_script(
+            vec![(0, press(KeyCode::Char('q'))), (1, press(KeyCode::Esc))],
+            2,
+            GameState::EnterInitials,
+        );
+        assert_eq!(frames[0], vec![InputAction::TypeChar('q')]);
+        assert_eq!(frames[1], vec![InputAction::Confirm]);
+    }
+
+    #[test]
+    fn test_menu_keys_navigate() {
+        let frames = run_script(
+            vec![
+                (0, press(KeyCode::Down)),
+                (0, press(KeyCode::Enter)),
+                (1, press(KeyCode::Esc)),
+            ],
+            2,
+            GameState::MainMenu,
+        );
+        assert_eq!(frames[0], vec![InputAction::MenuDown, InputAction::Confirm]);
+        assert_eq!(frames[1], vec![InputAction::Back]);
+    }
+
+    #[test]
+    fn test_steer_toward_has_a_dead_zone_around_the_ship() {
+        // Ship centre is at (12, 11)
+        let ship = Rect::new(10, 10, 5, 3);
+        assert_eq!(steer_toward((12, 11), ship, 0), (0, 0));
+        assert_eq!(steer_toward((13, 10), ship, 0), (0, 0));
+        assert_eq!(steer_toward((11, 12), ship, 0), (0, 0));
+        assert_eq!(steer_toward((14, 13), ship, 0), (1, 1));
+        assert_eq!(steer_toward((10, 2), ship, 0), (-1, -1));
+    }
+
+    #[test]
+    fn test_steer_toward_measures_from_inside_the_border() {
+        let ship = Rect::new(10, 10, 5, 3);
+        // Columns 0..=20 are the edge and its border, so game column 12 is terminal column 33
+        assert_eq!(steer_toward((33, 11), ship, 20), (0, 0));
+        assert_eq!(steer_toward((35, 11), ship, 20), (1, 0));
+        assert_eq!(steer_toward((12, 11), ship, 20), (-1, 0));
+    }
+
+    #[test]
+    fn test_mouse_buttons_fire_and_bomb_in_play() {
+        let mut input = scripted(vec![
+            (0, mouse(MouseEventKind::Down(MouseButton::Left))),
+            (2, mouse(MouseEventKind::Up(MouseButton::Left))),
+            (3, mouse(MouseEventKind::Down(MouseButton::Right))),
+        ]);
+        input.set_mouse_enabled(true);
+        let frames = run_frames(&mut input, 4, GameState::Playing);
+        // Fire is held while the left button is down
+        assert_eq!(frames[0], vec![InputAction::Fire]);
+        assert_eq!(frames[1], vec![InputAction::Fire]);
+        assert_eq!(frames[2], vec![]);
+        assert_eq!(frames[3], vec![InputAction::Bomb]);
+        assert_eq!(input.get_mouse_position(), Some((30, 20)));
+    }
+
+    #[test]
+    fn test_mouse_navigates_menus() {
+        let left = MouseEventKind::Down(MouseButton::Left);
+        let right = MouseEventKind::Down(MouseButton::Right);
+        let cases = [
+            (GameState::Title, left, Some(InputAction::Confirm)),
+            (GameState::GameOver, left, Some(InputAction::Confirm)),
+            (GameState::Title, right, None),
+            (GameState::Paused, left, Some(InputAction::Confirm)),
+            (GameState::Paused, right, Some(InputAction::Back)),
+            (
+                GameState::MainMenu,
+                MouseEventKind::ScrollUp,
+                Some(InputAction::MenuUp),
+            ),
+            (
+                GameState::Settings,
+                MouseEventKind::ScrollDown,
+                Some(InputAction::MenuDown),
+            ),
+            (
+                GameState::ShipSelect,
+                MouseEventKind::ScrollUp,
+                Some(InputAction::MenuLeft),
+            ),
+            (
+                GameState::ShipSelect,
+                MouseEventKind::ScrollDown,
+                Some(InputAction::MenuRight),
+            ),
+            (GameState::EnterInitials, left, None),
+        ];
+        for (state, kind, expected) in cases {
+            let mut input = scripted(vec![(0, mouse(kind))]);
+            input.set_mouse_enabled(true);
+            let frames = run_frames(&mut input, 1, state);
+            assert_eq!(
+                frames[0],
+                Vec::from_iter(expected),
+                "{:?} in {:?}",
+                kind,
+                state
+            );
+        }
+    }
+
+    #[test]
+    fn test_disabling_the_mouse_stops_firing() {
+        let mut input = scripted(vec![
+            (0, mouse(MouseEventKind::Down(MouseButton::Left))),
+            (2, mouse(MouseEventKind::Down(MouseButton::Left))),
+        ]);
+        input.set_mouse_enabled(true);
+        assert_eq!(
+            run_frames(&mut input, 1, GameState::Playing)[0],
+            vec![InputAction::Fire]
+        );
+
+        input.set_mouse_enabled(false);
+        assert_eq!(input.get_mouse_position(), None);
+        // Mouse events are ignored while disabled
+        let frames = run_frames(&mut input, 2, GameState::Playing);
+        assert_eq!(frames, vec![Vec::new(), Vec::new()]);
+    }
+}
@@ -0,0 +1,147 @@
+use crossterm::event::{self, Event};
+use std::collections::VecDeque;
+use std::io;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+/// Somewhere terminal events come from: the real terminal, a script or another thread
+pub trait InputSource {
+    /// Returns the next waiting event without blocking, or None once there are no more
+    /// for this frame
+    fn poll_event(&mut self) -> io::Result<Option<Event>>;
+}
+
+/// Reads events from the terminal through crossterm
+#[derive(Debug, Default)]
+pub struct CrosstermSource;
+
+impl InputSource for CrosstermSource {
+    fn poll_event(&mut self) -> io::Result<Option<Event>> {
+        if event::poll(Duration::from_millis(0))? {
+            event::read().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// An event to deliver on a given frame of a script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptedEvent {
+    /// Frame the event arrives on, counting polls from 0
+    pub frame: u64,
+    pub event: Event,
+}
+
+/// Plays back a fixed list of events frame by frame, for tests, replays and demos
+/// Each frame ends the first time `poll_event` returns None
+#[derive(Debug, Default)]
+pub struct ScriptedSource {
+    events: VecDeque<ScriptedEvent>,
+    frame: u64,
+}
+
+impl ScriptedSource {
+    /// Creates a script from events in any order; events on the same frame keep their order
+    pub fn new(mut events: Vec<ScriptedEvent>) -> Self {
+        events.sort_by_key(|scripted| scripted.frame);
+        Self {
+            events: events.into(),
+            frame: 0,
+        }
+    }
+
+    /// The frame the next poll belongs to
+    pub fn get_frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Whether every event has been delivered
+    pub fn is_finished(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl InputSource for ScriptedSource {
+    fn poll_event(&mut self) -> io::Result<Option<Event>> {
+        match self.events.front() {
+            Some(next) if next.frame <= self.frame => {
+                Ok(self.events.pop_front().map(|scripted| scripted.event))
+            }
+            _ => {
+                self.frame += 1;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Receives events sent from elsewhere, e.g. a bot running on another thread
+#[derive(Debug)]
+pub struct ChannelSource {
+    receiver: Receiver<Event>,
+}
+
+impl ChannelSource {
+    /// Creates a source along with the sender that feeds it
+    pub fn channel() -> (Sender<Event>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        (sender, Self { receiver })
+    }
+}
+
+impl InputSource for ChannelSource {
+    fn poll_event(&mut self) -> io::Result<Option<Event>> {
+        // A dropped sender just means no more input
+        Ok(self.receiver.try_recv().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn key(c: char) -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+    }
+
+    fn drain(source: &mut impl InputSource) -> Vec<Event> {
+        std::iter::from_fn(|| source.poll_event().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_scripted_source_delivers_events_on_their_frame() {
+        let mut source = ScriptedSource::new(vec![
+            ScriptedEvent {
+                frame: 2,
+                event: key('b'),
+            },
+            ScriptedEvent {
+                frame: 0,
+                event: key('a'),
+            },
+            ScriptedEvent {
+                frame: 2,
+                event: key('c'),
+            },
+        ]);
+
+        assert_eq!(drain(&mut source), vec![key('a')]);
+        assert_eq!(drain(&mut source), vec![]);
+        assert_eq!(source.get_frame(), 2);
+        assert_eq!(drain(&mut source), vec![key('b'), key('c')]);
+        assert!(source.is_finished());
+    }
+
+    #[test]
+    fn test_channel_source_passes_sent_events() {
+        let (sender, mut source) = ChannelSource::channel();
+        sender.send(key('x')).unwrap();
+        sender.send(key('y')).unwrap();
+        assert_eq!(drain(&mut source), vec![key('x'), key('y')]);
+
+        drop(sender);
+        assert_eq!(source.poll_event().unwrap(), None);
+    }
+}
@@ -1,10 +1,18 @@
 // Library exports for testing
 pub use entities::{
-    Enemy, EnemyType, Formation, FormationType, GameState, Pickup, Player, Projectile,
-    ProjectileOwner, ProjectileType, WeaponType,
+    DropTable, Enemy, EnemyType, Formation, FormationType, GameState, LASER_MAX_HEAT, LaserBeam,
+    Pickup, PickupKind, Player, Projectile, ProjectileInteraction, ProjectileOwner, ProjectileType,
+    SHOOTABLE_ENEMY_PROJECTILES, WeaponType,
 };
 
 pub mod audio;
+pub mod bindings;
 pub mod entities;
+pub mod high_scores;
 pub mod input;
+pub mod input_source;
+pub mod menu;
 pub mod renderer;
+pub mod run_stats;
+pub mod settings;
+pub mod storage;
@@ -1,22 +1,53 @@
 mod app;
 mod audio;
+mod bindings;
 mod entities;
+mod high_scores;
 mod input;
+// The scripted and channel sources are for tests, replays and bots built on the library
+#[allow(dead_code)]
+mod input_source;
+mod menu;
 mod renderer;
+mod run_stats;
+mod settings;
+mod storage;
 
 use app::App;
 use crossterm::{
-    event::{KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    event::{
+        DisableMouseCapture, EnableMouseCapture, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
+use settings::Settings;
 use std::io::Write;
 use std::{fs::OpenOptions, io::stdout};
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
 
+    // Saved settings, with command-line flags taking precedence for this session only
+    let settings_path = Settings::default_path();
+    let mut saved_settings = settings_path
+        .as_deref()
+        .map(Settings::load)
+        .unwrap_or_default();
+    // Key bindings the config couldn't read keep their defaults; say which before the
+    // game takes over the screen
+    for warning in saved_settings.bindings.take_load_warnings() {
+        eprintln!("config.toml: {}", warning);
+    }
+    let mut settings = saved_settings.clone();
+    if let Err(err) = settings.apply_args(std::env::args().skip(1)) {
+        eprintln!("{}", err);
+        eprintln!("{}", Settings::USAGE);
+        std::process::exit(2);
+    }
+
     let supports_keyboard_enhancement = matches!(
         crossterm::terminal::supports_keyboard_enhancement(),
         Ok(true)
@@ -46,18 +77,33 @@ fn main() -> color_eyre::Result<()> {
             PushKeyboardEnhancementFlags(
                 KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
                     | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+                    // Needed for Shift press/release events (focus mode)
+                    | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES
             )
         )?;
         writeln!(debug_file, "Keyboard enhancement flags pushed")?;
     }
 
+    // Mouse control needs the terminal to report mouse events; the app toggles this later if
+    // the setting changes
+    if settings.mouse_control {
+        execute!(stdout, EnableMouseCapture)?;
+    }
+
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = App::new().run(&mut terminal);
+    let result = App::new_with_settings(
+        settings,
+        saved_settings,
+        settings_path,
+        supports_keyboard_enhancement,
+    )
+    .run(&mut terminal);
 
     // Cleanup
     disable_raw_mode()?;
+    execute!(terminal.backend_mut(), DisableMouseCapture)?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
 
     if supports_keyboard_enhancement {
@@ -0,0 +1,233 @@
+use crate::bindings::Control;
+
+/// Entries on the main menu, in display order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MainMenuItem {
+    Start,
+    /// Cycles the ship handling model
+    Mode,
+    /// Switches between a solo run and local two-player co-op
+    Players,
+    Settings,
+    HighScores,
+    Quit,
+}
+
+impl MainMenuItem {
+    pub const ALL: [MainMenuItem; 6] = [
+        MainMenuItem::Start,
+        MainMenuItem::Mode,
+        MainMenuItem::Players,
+        MainMenuItem::Settings,
+        MainMenuItem::HighScores,
+        MainMenuItem::Quit,
+    ];
+
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            MainMenuItem::Start => "Start",
+            MainMenuItem::Mode => "Mode",
+            MainMenuItem::Players => "Players",
+            MainMenuItem::Settings => "Settings",
+            MainMenuItem::HighScores => "High Scores",
+            MainMenuItem::Quit => "Quit",
+        }
+    }
+}
+
+/// Entries on the pause menu, in display order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PauseMenuItem {
+    Resume,
+    Restart,
+    Settings,
+    Controls,
+    QuitToMenu,
+    Quit,
+}
+
+impl PauseMenuItem {
+    pub const ALL: [PauseMenuItem; 6] = [
+        PauseMenuItem::Resume,
+        PauseMenuItem::Restart,
+        PauseMenuItem::Settings,
+        PauseMenuItem::Controls,
+        PauseMenuItem::QuitToMenu,
+        PauseMenuItem::Quit,
+    ];
+
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            PauseMenuItem::Resume => "Resume",
+            PauseMenuItem::Restart => "Restart",
+            PauseMenuItem::Settings => "Settings",
+            PauseMenuItem::Controls => "Controls",
+            PauseMenuItem::QuitToMenu => "Quit to Menu",
+            PauseMenuItem::Quit => "Quit",
+        }
+    }
+
+    /// Choices that throw away the current run ask for confirmation first
+    pub fn is_destructive(&self) -> bool {
+        matches!(
+            self,
+            PauseMenuItem::Restart | PauseMenuItem::QuitToMenu | PauseMenuItem::Quit
+        )
+    }
+
+    /// Question asked before a destructive choice goes ahead
+    pub fn get_confirmation(&self) -> &'static str {
+        match self {
+            PauseMenuItem::Restart => "Restart this run?",
+            PauseMenuItem::QuitToMenu => "Abandon this run and return to the menu?",
+            PauseMenuItem::Quit => "Quit the game?",
+            _ => "Are you sure?",
+        }
+    }
+}
+
+/// Entries on the settings screen, in display order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingsItem {
+    Volume,
+    Mute,
+    RenderMode,
+    ShowFps,
+    Difficulty,
+    ScreenShake,
+    Particles,
+    Theme,
+    FloatingText,
+    Handling,
+    Mouse,
+    /// Opens the key bindings screen
+    Controls,
+    Back,
+}
+
+impl SettingsItem {
+    pub const ALL: [SettingsItem; 13] = [
+        SettingsItem::Volume,
+        SettingsItem::Mute,
+        SettingsItem::RenderMode,
+        SettingsItem::ShowFps,
+        SettingsItem::Difficulty,
+        SettingsItem::ScreenShake,
+        SettingsItem::Particles,
+        SettingsItem::Theme,
+        SettingsItem::FloatingText,
+        SettingsItem::Handling,
+        SettingsItem::Mouse,
+        SettingsItem::Controls,
+        SettingsItem::Back,
+    ];
+
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            SettingsItem::Volume => "Volume",
+            SettingsItem::Mute => "Mute",
+            SettingsItem::RenderMode => "Sprites",
+            SettingsItem::ShowFps => "Show FPS",
+            SettingsItem::Difficulty => "Difficulty",
+            SettingsItem::ScreenShake => "Screen shake",
+            SettingsItem::Particles => "Particles",
+            SettingsItem::Theme => "Theme",
+            SettingsItem::FloatingText => "Score popups",
+            SettingsItem::Handling => "Handling",
+            SettingsItem::Mouse => "Mouse control",
+            SettingsItem::Controls => "Controls",
+            SettingsItem::Back => "Back",
+        }
+    }
+}
+
+/// Rows on the controls screen: every bindable control, then the reset and back entries
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ControlsItem {
+    Bind(Control),
+    ResetDefaults,
+    Back,
+}
+
+impl ControlsItem {
+    pub fn get_all() -> Vec<ControlsItem> {
+        Control::ALL
+            .into_iter()
+            .map(ControlsItem::Bind)
+            .chain([ControlsItem::ResetDefaults, ControlsItem::Back])
+            .collect()
+    }
+
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            ControlsItem::Bind(control) => control.get_label(),
+            ControlsItem::ResetDefaults => "Reset to defaults",
+            ControlsItem::Back => "Back",
+        }
+    }
+}
+
+/// Highlighted entry in a list of menu items, wrapping at both ends
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MenuCursor {
+    pub index: usize,
+}
+
+impl MenuCursor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next(&mut self, len: usize) {
+        if len > 0 {
+            self.index = (self.index + 1) % len;
+        }
+    }
+
+    pub fn previous(&mut self, len: usize) {
+        if len > 0 {
+            self.index = (self.index + len - 1) % len;
+        }
+    }
+
+    /// The highlighted item from a menu's item list
+    pub fn get_selected<T: Copy>(&self, items: &[T]) -> T {
+        items[self.index.min(items.len() - 1)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_menu_cursor_wraps() {
+        let mut cursor = MenuCursor::new();
+        cursor.previous(MainMenuItem::ALL.len());
+        assert_eq!(cursor.get_selected(&MainMenuItem::ALL), MainMenuItem::Quit);
+        cursor.next(MainMenuItem::ALL.len());
+        assert_eq!(cursor.get_selected(&MainMenuItem::ALL), MainMenuItem::Start);
+    }
+
+    #[test]
+    fn test_pause_menu_confirms_destructive_choices() {
+        let destructive: Vec<PauseMenuItem> = PauseMenuItem::ALL
+            .into_iter()
+            .filter(|item| item.is_destructive())
+            .collect();
+        assert_eq!(
+            destructive,
+            vec![
+                PauseMenuItem::Restart,
+                PauseMenuItem::QuitToMenu,
+                PauseMenuItem::Quit
+            ]
+        );
+    }
+
+    #[test]
+    fn test_menu_cursor_clamps_to_shorter_menu() {
+        let cursor = MenuCursor { index: 12 };
+        assert_eq!(cursor.get_selected(&MainMenuItem::ALL), MainMenuItem::Quit);
+    }
+}
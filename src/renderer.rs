@@ -1,38 +1,142 @@
+use crate::bindings::Control;
 use crate::entities::{
-    Enemy, EnemyType, GameState, Particle, Pickup, Player, Projectile, ProjectileOwner,
-    ProjectileType,
+    CHAIN_WINDOW_FRAMES, DashState, Enemy, EnemyType, FloatingText, FloatingTextKind, GameState,
+    LASER_MAX_HEAT, LaserBeam, MovementModel, Particle, Pickup, PickupKind, Player, Projectile,
+    ProjectileOwner, ProjectileType, SHIPS, SUBCELLS_PER_CELL, ScoreChain, WeaponType,
 };
+use crate::high_scores::{HighScoreTable, InitialsEntry, MAX_HIGH_SCORES, format_date};
+use crate::menu::{ControlsItem, MainMenuItem, MenuCursor, PauseMenuItem, SettingsItem};
+use crate::run_stats::RunStats;
+use crate::settings::{Palette, RenderMode, Settings};
 use rand::Rng;
 use ratatui::{
     Frame,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Flex, Layout, Margin, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
 };
 use ratatui_image::{StatefulImage, picker::Picker, protocol::StatefulProtocol};
 
+/// Logo drawn on the title screen
+const TITLE_LOGO: &[&str] = &[
+    r"  ____    _    _        _    ____ ___    _    ",
+    r" / ___|  / \  | |      / \  / ___|_ _|  / \   ",
+    r"| |  _  / _ \ | |     / _ \| |  _ | |  / _ \  ",
+    r"| |_| |/ ___ \| |___ / ___ \ |_| || | / ___ \ ",
+    r" \____/_/   \_\_____/_/   \_\____|___/_/   \_\",
+];
+
+/// Colours the title logo cycles through
+const TITLE_COLORS: &[Color] = &[
+    Color::LightCyan,
+    Color::Cyan,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::Magenta,
+    Color::LightRed,
+];
+
+/// Controls listed in the in-game key hint after movement, in order
+const HUD_HINT_CONTROLS: &[Control] = &[
+    Control::Fire,
+    Control::Bomb,
+    Control::Dash,
+    Control::Focus,
+    Control::ToggleMovement,
+    Control::ToggleFloatingText,
+    Control::Pause,
+    Control::Quit,
+];
+
 /// View struct that holds all game state needed for rendering
 pub struct RenderView<'a> {
-    pub game_state: GameState,
-    pub player: &'a Player,
+    /// Screens to draw, bottom first (see ScreenStack::get_visible)
+    pub screens: &'a [GameState],
+    pub main_menu: MenuCursor,
+    pub settings_menu: MenuCursor,
+    pub pause_menu: MenuCursor,
+    /// Pause menu choice the confirmation prompt is asking about
+    pub pending_confirmation: Option<PauseMenuItem>,
+    pub confirm_yes: bool,
+    pub controls_menu: MenuCursor,
+    /// Control waiting for a new key on the controls screen
+    pub rebinding: Option<Control>,
+    /// Message about the last change on the controls screen
+    pub binding_notice: Option<&'a str>,
+    pub settings: &'a Settings,
+    /// Ships in the run, indexed by player id; two in co-op
+    pub players: &'a [Player],
     pub enemies: &'a [Enemy],
     pub projectiles: &'a [Projectile],
     pub particles: &'a [Particle],
+    pub floating_texts: &'a [FloatingText],
     pub pickups: &'a [Pickup],
+    pub laser_beams: &'a [LaserBeam],
+    pub movement_model: MovementModel,
+    pub selected_ship: usize,
+    /// Whether the next run is local two-player co-op
+    pub co_op: bool,
+    /// Player picking a ship on the selection screen, 0 for player one
+    pub choosing_player: usize,
+    /// Score for the whole run, both players' together in co-op
     pub score: u32,
+    /// Frames left of screen shake, 0 when still
+    pub shake_frames: u8,
+    pub stats: &'a RunStats,
+    pub high_scores: &'a HighScoreTable,
+    pub initials: InitialsEntry,
+    /// Where this run landed in the high-score table, if it made it
+    pub last_rank: Option<usize>,
     pub frame_count: u64,
+    pub ui_frame_count: u64,
     pub area: Rect,
     pub edge_width: u16,
     pub fps: u32,
     pub elapsed_time_secs: u64,
 }
 
+/// Sprite images used in image render mode
+struct SpriteImages {
+    /// One image per hull, in SHIPS order
+    ships: Vec<StatefulProtocol>,
+    dark_fighter: StatefulProtocol,
+    dark_tanker: StatefulProtocol,
+}
+
+impl SpriteImages {
+    /// Queries the terminal's graphics support and loads every sprite
+    fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        // Create the image protocol picker
+        let picker = Picker::from_query_stdio()?;
+
+        // Load every selectable ship's image
+        let ships = SHIPS
+            .iter()
+            .map(|ship| Ok(picker.new_resize_protocol(image::open(ship.sprite_path)?)))
+            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+        // Load the dark fighter image (Fast enemy)
+        let fighter_img = image::open("assests/sprites/dark-fighter.png")?;
+        let dark_fighter = picker.new_resize_protocol(fighter_img);
+
+        // Load the dark tanker image (Tank enemy)
+        let tanker_img = image::open("assests/sprites/dark-tanker.png")?;
+        let dark_tanker = picker.new_resize_protocol(tanker_img);
+
+        Ok(Self {
+            ships,
+            dark_fighter,
+            dark_tanker,
+        })
+    }
+}
+
 /// Handles all rendering responsibilities for the game
 pub struct GameRenderer {
-    ship_image: StatefulProtocol,
-    dark_fighter_image: StatefulProtocol,
-    dark_tanker_image: StatefulProtocol,
+    /// Loaded sprite images, None if the terminal can't display them
+    images: Option<SpriteImages>,
+    render_mode: RenderMode,
 }
 
 impl Default for GameRenderer {
@@ -43,43 +147,69 @@ impl Default for GameRenderer {
 
 impl GameRenderer {
     /// Creates a new GameRenderer
+    /// Falls back to ASCII sprites if the images can't be loaded or displayed
     pub fn new() -> Self {
-        // Create the image protocol picker
-        let picker = Picker::from_query_stdio().expect("Failed to create picker");
-
-        // Load the ship image
-        let ship_img = image::open("assests/sprites/ship.png").expect("Failed to load ship.png");
-        let ship_image = picker.new_resize_protocol(ship_img);
-
-        // Load the dark fighter image (Fast enemy)
-        let fighter_img = image::open("assests/sprites/dark-fighter.png")
-            .expect("Failed to load dark-fighter.png");
-        let dark_fighter_image = picker.new_resize_protocol(fighter_img);
+        match SpriteImages::load() {
+            Ok(images) => Self {
+                images: Some(images),
+                render_mode: RenderMode::Image,
+            },
+            Err(_) => Self {
+                images: None,
+                render_mode: RenderMode::Ascii,
+            },
+        }
+    }
 
-        // Load the dark tanker image (Tank enemy)
-        let tanker_img =
-            image::open("assests/sprites/dark-tanker.png").expect("Failed to load dark-tanker.png");
-        let dark_tanker_image = picker.new_resize_protocol(tanker_img);
+    /// Switches between sprite images and ASCII, staying on ASCII if images aren't available
+    pub fn set_render_mode(&mut self, render_mode: RenderMode) {
+        self.render_mode = if self.images.is_some() {
+            render_mode
+        } else {
+            RenderMode::Ascii
+        };
+    }
 
-        Self {
-            ship_image,
-            dark_fighter_image,
-            dark_tanker_image,
+    /// Main render method, drawing each visible screen from the bottom of the stack up
+    pub fn render(&mut self, frame: &mut Frame, view: &RenderView) {
+        for &screen in view.screens {
+            self.render_screen(frame, view, screen);
         }
     }
 
-    /// Main render method that dispatches to state-specific renderers
-    pub fn render(&mut self, frame: &mut Frame, view: &RenderView) {
-        match view.game_state {
+    /// Dispatches to the renderer for a single screen
+    fn render_screen(&mut self, frame: &mut Frame, view: &RenderView, screen: GameState) {
+        match screen {
+            GameState::Title => self.render_title(frame, view),
+            GameState::MainMenu => self.render_main_menu(frame, view),
+            GameState::HighScores => self.render_high_score_screen(frame, view),
+            GameState::Settings => self.render_settings(frame, view),
+            GameState::ShipSelect => self.render_ship_select(frame, view),
             GameState::Playing => self.render_game(frame, view),
             GameState::Paused => self.render_paused(frame, view),
-            GameState::GameOver => self.render_game_over(frame, view),
+            GameState::Controls => self.render_controls(frame, view),
+            GameState::Confirmation => self.render_confirmation(frame, view),
+            GameState::EnterInitials | GameState::GameOver => {
+                self.render_game_over(frame, view, screen)
+            }
         }
     }
 
     /// Renders the active gameplay screen
     fn render_game(&mut self, frame: &mut Frame, view: &RenderView) {
-        let area = view.area;
+        let palette = view.settings.theme.get_palette();
+
+        // Shake the whole play area a column either way after a hit
+        let mut area = view.area;
+        if view.shake_frames > 0 && area.width > 2 {
+            let shift = if view.frame_count.is_multiple_of(2) {
+                1
+            } else {
+                -1
+            };
+            area.x = area.x.saturating_add_signed(shift);
+            area.width -= 1;
+        }
 
         // Create a narrower centered game area with borders
         let game_area = if view.edge_width > 0 {
@@ -96,7 +226,7 @@ impl GameRenderer {
             // Render block with borders around the narrowed area
             let block = Block::default()
                 .borders(Borders::LEFT | Borders::RIGHT)
-                .border_style(Style::default().fg(Color::DarkGray));
+                .border_style(Style::default().fg(palette.border));
             let inner = block.inner(centered_area);
             frame.render_widget(block, centered_area);
             inner
@@ -119,25 +249,82 @@ impl GameRenderer {
             );
         }
 
-        // Render player - using ship.png image
-        if view.player.is_alive() {
-            let player_width = view.player.get_width();
-            let player_height = view.player.get_height();
+        // Sprite images are only used in image mode
+        let mut images = match self.render_mode {
+            RenderMode::Image => self.images.as_mut(),
+            RenderMode::Ascii => None,
+        };
+
+        // Render each ship still flying - using its image, or the ASCII ship
+        for player in view.players.iter().filter(|player| player.is_alive()) {
+            let player_width = player.get_width();
+            let player_height = player.get_height();
 
             let player_area = Rect {
-                x: game_area.x + view.player.x,
-                y: game_area.y + view.player.y,
+                x: game_area.x + player.x,
+                y: game_area.y + player.y,
                 width: player_width,
                 height: player_height,
             };
 
-            // Render the ship image if it fits in the game area
-            if view.player.y + player_height <= game_area.height
-                && view.player.x + player_width < game_area.width
+            // Render the ship if it fits in the game area
+            if player.y + player_height <= game_area.height
+                && player.x + player_width < game_area.width
             {
-                // Render the ship image using StatefulImage widget
-                let image_widget = StatefulImage::default();
-                frame.render_stateful_widget(image_widget, player_area, &mut self.ship_image);
+                match &mut images {
+                    Some(images) => {
+                        // Render the ship image using StatefulImage widget
+                        let image_widget = StatefulImage::default();
+                        frame.render_stateful_widget(
+                            image_widget,
+                            player_area,
+                            &mut images.ships[player.ship_index],
+                        );
+                    }
+                    None => {
+                        let color = if player.is_flashing() {
+                            Color::White
+                        } else if player.is_dashing() {
+                            Color::LightCyan
+                        } else if player.id == 0 {
+                            palette.player
+                        } else {
+                            palette.player_two
+                        };
+                        Self::render_ascii_sprite(
+                            frame,
+                            &player.get_sprite_lines(),
+                            player_area,
+                            color,
+                        );
+                    }
+                }
+            }
+
+            // Focus mode shows the ship's real hitbox
+            if player.focused {
+                let hitbox = player_area.intersection(game_area);
+                frame
+                    .buffer_mut()
+                    .set_style(hitbox, Style::default().bg(Color::Rgb(96, 0, 0)));
+            }
+
+            // Shield bubble around the ship, flashing while it runs out
+            if player.is_shielded() && (!player.is_shield_expiring() || view.frame_count % 8 < 4) {
+                let bubble = Rect {
+                    x: player_area.x.saturating_sub(1),
+                    y: player_area.y.saturating_sub(1),
+                    width: player_width + 2,
+                    height: player_height + 2,
+                }
+                .intersection(game_area);
+
+                frame.render_widget(
+                    Block::bordered()
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(Color::LightBlue)),
+                    bubble,
+                );
             }
         }
 
@@ -156,44 +343,43 @@ impl GameRenderer {
             // Check if enemy fits in game area
             if enemy.y + enemy_height <= game_area.height && enemy.x + enemy_width < game_area.width
             {
-                match enemy.enemy_type {
-                    EnemyType::Fast => {
+                match (enemy.enemy_type, &mut images) {
+                    (EnemyType::Fast, Some(images)) => {
                         // Render dark fighter sprite
                         let image_widget = StatefulImage::default();
                         frame.render_stateful_widget(
                             image_widget,
                             enemy_area,
-                            &mut self.dark_fighter_image,
+                            &mut images.dark_fighter,
                         );
                     }
-                    EnemyType::Tank => {
+                    (EnemyType::Tank, Some(images)) => {
                         // Render dark tanker sprite
                         let image_widget = StatefulImage::default();
                         frame.render_stateful_widget(
                             image_widget,
                             enemy_area,
-                            &mut self.dark_tanker_image,
+                            &mut images.dark_tanker,
                         );
                     }
-                    EnemyType::Basic => {
-                        // Use ASCII rendering for Basic enemies
-                        let sprite_lines = enemy.get_sprite_lines();
+                    (enemy_type, _) => {
+                        // Use ASCII rendering for Basic enemies and when images are off
                         let color = if enemy.is_flashing() {
                             Color::White
                         } else {
-                            Color::Red
+                            match enemy_type {
+                                EnemyType::Basic => Color::Red,
+                                EnemyType::Fast => Color::Magenta,
+                                EnemyType::Tank => Color::Yellow,
+                            }
                         };
 
-                        let text: Vec<Line> = sprite_lines
-                            .iter()
-                            .map(|line| {
-                                Line::from(*line).style(
-                                    Style::default().fg(color).add_modifier(Modifier::BOLD),
-                                )
-                            })
-                            .collect();
-
-                        frame.render_widget(Paragraph::new(text), enemy_area);
+                        Self::render_ascii_sprite(
+                            frame,
+                            &enemy.get_sprite_lines(),
+                            enemy_area,
+                            color,
+                        );
                     }
                 }
             }
@@ -201,13 +387,55 @@ impl GameRenderer {
 
         // Render projectiles - optimized with direct buffer access
         let buffer = frame.buffer_mut();
+
+        // Render laser beams as a full column from the ship to whatever they hit
+        for beam in view
+            .laser_beams
+            .iter()
+            .filter(|beam| beam.x < game_area.width)
+        {
+            for y in beam.top_y..=beam.bottom_y.min(game_area.height.saturating_sub(1)) {
+                let (char, color) = if y == beam.top_y && beam.hit_enemy.is_some() {
+                    ('*', Color::White)
+                } else {
+                    ('┃', Color::LightCyan)
+                };
+                buffer.set_string(
+                    game_area.x + beam.x,
+                    game_area.y + y,
+                    char.to_string(),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                );
+            }
+        }
         for projectile in view.projectiles {
+            // Sword slashes render as an arc with a bright leading edge
+            if projectile.projectile_type == ProjectileType::Slash {
+                let cells = projectile.hit_cells();
+                for (i, (x, y)) in cells.iter().enumerate() {
+                    if *x < game_area.width && *y < game_area.height {
+                        let (char, color) = if i + 1 == cells.len() {
+                            (')', Color::White)
+                        } else {
+                            ('~', Color::Cyan)
+                        };
+                        buffer.set_string(
+                            game_area.x + x,
+                            game_area.y + y,
+                            char.to_string(),
+                            Style::default().fg(color).add_modifier(Modifier::BOLD),
+                        );
+                    }
+                }
+                continue;
+            }
+
             if projectile.x < game_area.width && projectile.y < game_area.height {
                 let (char, color) = match (&projectile.projectile_type, &projectile.owner) {
-                    (ProjectileType::Bullet, ProjectileOwner::Player) => ('|', Color::Yellow),
-                    (ProjectileType::Slash, ProjectileOwner::Player) => ('~', Color::Cyan),
-                    (ProjectileType::BugShot, ProjectileOwner::Player) => ('•', Color::Green),
-                    (ProjectileType::BomberProjectile, ProjectileOwner::Player) => {
+                    (ProjectileType::Bullet, ProjectileOwner::Player(_)) => ('|', Color::Yellow),
+                    (ProjectileType::Slash, ProjectileOwner::Player(_)) => ('~', Color::Cyan),
+                    (ProjectileType::BugShot, ProjectileOwner::Player(_)) => ('•', Color::Green),
+                    (ProjectileType::BomberProjectile, ProjectileOwner::Player(_)) => {
                         // Blinking effect when near explosion
                         if projectile.lifetime.unwrap_or(1) <= 10 {
                             ('O', Color::Red)
@@ -215,6 +443,18 @@ impl GameRenderer {
                             ('O', Color::LightRed)
                         }
                     }
+                    (ProjectileType::Missile, ProjectileOwner::Player(_)) => {
+                        // Point the glyph along the missile's heading
+                        let char = match projectile.heading {
+                            0 => '^',
+                            1 | 5 => '/',
+                            2 => '>',
+                            3 | 7 => '\\',
+                            4 => 'v',
+                            _ => '<',
+                        };
+                        (char, Color::LightBlue)
+                    }
                     (_, ProjectileOwner::Enemy) => ('!', Color::Magenta),
                 };
 
@@ -256,36 +496,51 @@ impl GameRenderer {
                     game_area.y + pickup.y,
                     pickup.get_char().to_string(),
                     Style::default()
-                        .fg(Color::White)
+                        .fg(Self::pickup_color(pickup.kind))
                         .add_modifier(Modifier::BOLD),
                 );
             }
         }
 
-        // Stats overlay at the top - left side
-        let stats_left = Line::from(vec![
-            Span::styled("Score: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(
-                format!("{}", view.score),
+        // Floating score popups and damage numbers, drawn over everything in the game area
+        for text in view.floating_texts {
+            let width = text.text.chars().count() as u16;
+            let x = text.x.saturating_sub(width / 2);
+            if text.y >= game_area.height || x >= game_area.width {
+                continue;
+            }
+
+            let style = if text.is_fading() {
+                Style::default().fg(Color::DarkGray)
+            } else {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(Self::floating_text_color(text.kind))
+                    .add_modifier(Modifier::BOLD)
+            };
+            // Clip to the game area so popups never spill onto the border
+            let visible: String = text
+                .text
+                .chars()
+                .take((game_area.width - x) as usize)
+                .collect();
+            buffer.set_string(game_area.x + x, game_area.y + text.y, visible, style);
+        }
+
+        // Stats overlay at the top - left side, with player one's ship in co-op
+        let co_op = view.players.len() > 1;
+        let mut stats_left = Line::default();
+        if co_op {
+            stats_left.push_span(Span::styled(
+                "P1 ",
+                Style::default()
+                    .fg(palette.player)
                     .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled("  HP: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(
-                format!("{}%", view.player.health),
-                if view.player.health > 50 {
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD)
-                } else if view.player.health > 25 {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
-                },
-            ),
+            ));
+        }
+        stats_left
+            .spans
+            .extend(Self::player_status(&view.players[0]));
+        stats_left.spans.extend([
             Span::styled("  Enemies: ", Style::default().fg(Color::DarkGray)),
             Span::styled(
                 format!("{}", view.enemies.len()),
@@ -293,21 +548,19 @@ impl GameRenderer {
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled("  Weapon: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(
-                view.player.current_weapon.get_name(),
-                Style::default()
-                    .fg(Color::Magenta)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled("  FPS: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(
+        ]);
+        if view.settings.show_fps {
+            stats_left.push_span(Span::styled(
+                "  FPS: ",
+                Style::default().fg(Color::DarkGray),
+            ));
+            stats_left.push_span(Span::styled(
                 format!("{}", view.fps),
                 Style::default()
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
-            ),
-        ]);
+            ));
+        }
 
         let stats_area = Rect {
             x: area.x + 1,
@@ -340,61 +593,831 @@ impl GameRenderer {
 
         frame.render_widget(Paragraph::new(timer_text).centered(), timer_area);
 
-        // Controls hint at bottom
-        let controls = Line::from(vec![Span::styled(
-            "[WASD/Arrows: Move] [Space: Fire] [P: Pause] [Q: Quit]",
-            Style::default().fg(Color::DarkGray),
-        )]);
-
-        let controls_area = Rect {
+        let bottom_area = Rect {
             x: area.x + 1,
             y: area.y + area.height.saturating_sub(1),
             width: area.width.saturating_sub(2),
             height: 1,
         };
 
-        frame.render_widget(Paragraph::new(controls).centered(), controls_area);
+        let bindings = &view.settings.bindings;
+
+        // In co-op the bottom row is player two's stats, with each player's move and fire
+        // keys at the right, or just the move keys when the row is short
+        if let Some(player_two) = view.players.get(1) {
+            let mut stats = Line::from(Span::styled(
+                "P2 ",
+                Style::default()
+                    .fg(palette.player_two)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            stats.spans.extend(Self::player_status(player_two));
+
+            let players = [(0, Control::Fire), (1, Control::P2Fire)];
+            let hints = [true, false].map(|with_fire| {
+                players
+                    .map(|(player, fire)| {
+                        let mut hint = format!(
+                            "P{} [{}: Move]",
+                            player + 1,
+                            bindings.get_move_label(player, true)
+                        );
+                        if with_fire {
+                            let keys = bindings.get_active_keys_label(fire, true);
+                            hint.push_str(&format!(" [{}: Fire]", keys));
+                        }
+                        hint
+                    })
+                    .join("  ")
+            });
+            let room = (bottom_area.width as usize).saturating_sub(stats.width() + 2);
+            if let Some(hint) = hints.into_iter().find(|hint| hint.chars().count() <= room) {
+                let hint = Line::from(Span::styled(hint, Style::default().fg(Color::DarkGray)));
+                frame.render_widget(Paragraph::new(hint).right_aligned(), bottom_area);
+            }
+            frame.render_widget(Paragraph::new(stats), bottom_area);
+            return;
+        }
+
+        // Controls hint at bottom, built from the current key bindings
+        let mut hint = format!("[{}: Move]", bindings.get_move_label(0, false));
+        for &control in HUD_HINT_CONTROLS {
+            let label = match control {
+                Control::ToggleMovement => {
+                    format!("{} handling", view.movement_model.get_name())
+                }
+                _ => control.get_hint_label().to_string(),
+            };
+            hint.push_str(&format!(
+                " [{}: {}]",
+                bindings.get_keys_label(control),
+                label
+            ));
+        }
+        if view.settings.mouse_control {
+            hint.push_str(" [Mouse: Steer] [LMB: Fire] [RMB: Bomb]");
+        }
+        let controls = Line::from(vec![Span::styled(
+            hint,
+            Style::default().fg(Color::DarkGray),
+        )]);
+
+        frame.render_widget(Paragraph::new(controls).centered(), bottom_area);
     }
 
-    /// Renders the pause screen with overlay
-    fn render_paused(&mut self, frame: &mut Frame, view: &RenderView) {
-        // First render the game screen
-        self.render_game(frame, view);
+    /// Draws text sprite lines centered in the given area
+    fn render_ascii_sprite(frame: &mut Frame, lines: &[&str], area: Rect, color: Color) {
+        let text: Vec<Line> = lines
+            .iter()
+            .map(|line| {
+                Line::from(*line).style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+            })
+            .collect();
 
+        let sprite_height = (lines.len() as u16).min(area.height);
+        let sprite_area = Rect {
+            y: area.y + (area.height - sprite_height) / 2,
+            height: sprite_height,
+            ..area
+        };
+
+        frame.render_widget(Paragraph::new(text).centered(), sprite_area);
+    }
+
+    /// Colour used to draw each kind of pickup
+    fn pickup_color(kind: PickupKind) -> Color {
+        match kind {
+            PickupKind::Weapon(_) => Color::White,
+            PickupKind::Repair => Color::Green,
+            PickupKind::Shield => Color::LightBlue,
+            PickupKind::Bomb => Color::LightRed,
+            PickupKind::Medal => Color::Yellow,
+            PickupKind::SpeedBoost => Color::LightCyan,
+        }
+    }
+
+    fn floating_text_color(kind: FloatingTextKind) -> Color {
+        match kind {
+            FloatingTextKind::Score => Color::Yellow,
+            FloatingTextKind::Chain => Color::LightMagenta,
+            FloatingTextKind::Bonus => Color::LightGreen,
+            FloatingTextKind::Damage => Color::White,
+            FloatingTextKind::PlayerDamage => Color::Red,
+            FloatingTextKind::Pickup => Color::LightCyan,
+        }
+    }
+
+    /// Score, health, dash, bombs and weapon readout for one ship in the HUD
+    fn player_status(player: &Player) -> Vec<Span<'static>> {
+        if !player.is_alive() {
+            return vec![
+                Span::styled("Score: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!("{}", player.score),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    "  DOWN",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ),
+            ];
+        }
+
+        // Health as a percentage of the ship's maximum, which varies by hull
+        let health_percent = player.health as u16 * 100 / player.max_health.max(1) as u16;
+
+        vec![
+            Span::styled("Score: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{}", player.score),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                Self::chain_status(&player.score_chain),
+                Style::default()
+                    .fg(Color::LightMagenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  HP: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{}%", health_percent),
+                if health_percent > 50 {
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD)
+                } else if health_percent > 25 {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                },
+            ),
+            Span::styled(
+                Self::shield_status(player),
+                Style::default()
+                    .fg(Color::LightBlue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  Dash: ", Style::default().fg(Color::DarkGray)),
+            match player.dash_state {
+                DashState::Ready => Span::styled(
+                    "READY",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                DashState::Dashing { .. } => Span::styled(
+                    "GO",
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                DashState::Cooldown { frames_left } => Span::styled(
+                    format!("{:.1}s", frames_left as f32 / 60.0),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            },
+            Span::styled("  Bombs: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!("{}", player.bombs),
+                Style::default()
+                    .fg(Color::LightRed)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  Weapon: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!(
+                    "{} Lv{}",
+                    player.current_weapon.get_name(),
+                    player.weapon_level
+                ),
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                Self::heat_gauge(player),
+                if player.laser_overheated {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::LightCyan)
+                },
+            ),
+        ]
+    }
+
+    /// Remaining shield hits and seconds, empty when no shield is up
+    fn shield_status(player: &Player) -> String {
+        if !player.is_shielded() {
+            return String::new();
+        }
+        format!(
+            "  Shield: {}x {}s",
+            player.shield_hits,
+            player.shield_frames.div_ceil(60)
+        )
+    }
+
+    /// Multiplier and decaying chain timer, blank when no chain is running
+    fn chain_status(chain: &ScoreChain) -> String {
+        if !chain.is_active() {
+            return String::new();
+        }
+
+        const GAUGE_WIDTH: usize = 8;
+        let filled = chain.timer as usize * GAUGE_WIDTH / CHAIN_WINDOW_FRAMES as usize;
+        format!(
+            " x{} ({} chain) [{}{}]",
+            chain.get_multiplier(),
+            chain.chain,
+            "=".repeat(filled),
+            " ".repeat(GAUGE_WIDTH - filled)
+        )
+    }
+
+    /// Builds the laser heat gauge shown next to the weapon name
+    /// Empty when the laser isn't equipped
+    fn heat_gauge(player: &Player) -> String {
+        if player.current_weapon != WeaponType::Laser {
+            return String::new();
+        }
+
+        const GAUGE_WIDTH: usize = 10;
+        let filled = player.laser_heat as usize * GAUGE_WIDTH / LASER_MAX_HEAT as usize;
+        let label = if player.laser_overheated {
+            " OVERHEAT"
+        } else {
+            ""
+        };
+        format!(
+            " [{}{}]{}",
+            "#".repeat(filled),
+            "-".repeat(GAUGE_WIDTH - filled),
+            label
+        )
+    }
+
+    /// Renders the title screen: animated logo, attract demo and the top scores
+    fn render_title(&mut self, frame: &mut Frame, view: &RenderView) {
         let area = view.area;
-        let pause_text = vec![
-            Line::from(""),
-            Line::from("PAUSED").centered().bold().yellow(),
+        let tick = view.ui_frame_count;
+        frame.render_widget(Block::default().borders(Borders::ALL), area);
+
+        let inner = area.inner(Margin::new(2, 1));
+        let [logo_area, demo_area, scores_area, prompt_area] = Layout::vertical([
+            Constraint::Length(TITLE_LOGO.len() as u16 + 1),
+            Constraint::Fill(1),
+            Constraint::Length(6),
+            Constraint::Length(2),
+        ])
+        .areas(inner);
+
+        // The logo cycles through colours a line at a time
+        let logo: Vec<Line> = TITLE_LOGO
+            .iter()
+            .enumerate()
+            .map(|(row, line)| {
+                let color = TITLE_COLORS[(row + tick as usize / 8) % TITLE_COLORS.len()];
+                Line::from(*line).centered().fg(color).bold()
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(logo), logo_area);
+
+        Self::render_attract_demo(frame, demo_area, tick, view.settings.theme.get_palette());
+        Self::render_high_scores(frame, scores_area, view.high_scores, None);
+
+        // Blinking prompt
+        if (tick / 30).is_multiple_of(2) {
+            frame.render_widget(
+                Paragraph::new(Line::from("PRESS ANY KEY").centered().white().bold()),
+                prompt_area,
+            );
+        }
+    }
+
+    /// Attract-mode demo: a formation sways while a ship sweeps beneath it firing
+    fn render_attract_demo(frame: &mut Frame, area: Rect, tick: u64, palette: Palette) {
+        const ENEMY_SPACING: u16 = 10;
+        const SWAY: i32 = 6;
+        let demo_enemy = Enemy::new_in_formation(0, 0, EnemyType::Basic, 0, (0, 0));
+        let enemy_width = demo_enemy.get_width();
+        let enemy_count = (area.width / ENEMY_SPACING).clamp(1, 5);
+        let formation_width = enemy_count * ENEMY_SPACING;
+        if area.height < 8 || area.width < formation_width + SWAY as u16 * 2 {
+            return;
+        }
+
+        // Triangle wave from -SWAY to SWAY
+        let phase = (tick / 4 % (SWAY as u64 * 4)) as i32;
+        let sway = (phase - SWAY * 2).abs() - SWAY;
+        let formation_x = (area.x + (area.width - formation_width) / 2) as i32 + sway;
+        for i in 0..enemy_count {
+            let enemy_area = Rect {
+                x: (formation_x + (i * ENEMY_SPACING) as i32) as u16,
+                y: area.y,
+                width: enemy_width,
+                height: demo_enemy.get_height(),
+            };
+            Self::render_ascii_sprite(
+                frame,
+                &demo_enemy.get_sprite_lines(),
+                enemy_area,
+                Color::Red,
+            );
+        }
+
+        // The ship patrols back and forth under the formation
+        let ship = &SHIPS[0];
+        let sprite_width = ship.ascii_sprite[0].chars().count() as u16;
+        let travel = formation_width.saturating_sub(sprite_width) as u64;
+        let step = tick / 3 % (travel * 2).max(1);
+        let offset = if step > travel {
+            travel * 2 - step
+        } else {
+            step
+        } as u16;
+        let ship_x = area.x + (area.width - formation_width) / 2 + offset;
+        let ship_y = area.y + area.height - ship.ascii_sprite.len() as u16;
+        Self::render_ascii_sprite(
+            frame,
+            ship.ascii_sprite,
+            Rect::new(ship_x, ship_y, sprite_width, ship.ascii_sprite.len() as u16),
+            palette.player,
+        );
+
+        // Shots rising from the ship's nose
+        let gun_x = ship_x + sprite_width / 2;
+        let lane = ship_y.saturating_sub(area.y + 3);
+        let buffer = frame.buffer_mut();
+        for shot in 0..3u64 {
+            let rise = ((tick + shot * 7) % lane.max(1) as u64) as u16;
+            buffer.set_string(
+                gun_x,
+                ship_y - 1 - rise,
+                "|",
+                Style::default().fg(Color::Yellow),
+            );
+        }
+    }
+
+    /// Renders a vertical list of menu entries with the highlighted one marked
+    fn render_menu(
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        entries: &[String],
+        selected: usize,
+        palette: Palette,
+    ) {
+        let mut lines = vec![
+            Line::from(title.to_string())
+                .centered()
+                .fg(palette.highlight)
+                .bold(),
             Line::from(""),
-            Line::from("Press P to resume").centered().white(),
         ];
+        for (index, entry) in entries.iter().enumerate() {
+            let line = if index == selected {
+                Line::from(format!("> {} <", entry))
+                    .centered()
+                    .black()
+                    .bg(palette.highlight)
+                    .bold()
+            } else {
+                Line::from(entry.clone()).centered().white()
+            };
+            lines.push(line);
+        }
+
+        let height = (lines.len() as u16).min(area.height);
+        let [menu_area] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(area);
+        frame.render_widget(Paragraph::new(lines), menu_area);
+    }
+
+    /// Bordered full-screen frame with a key hint along the bottom, returning the space inside
+    fn render_menu_frame(
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        hint: &str,
+        palette: Palette,
+    ) -> Rect {
+        frame.render_widget(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title.to_string())
+                .title_alignment(Alignment::Center)
+                .border_style(Style::default().fg(palette.frame)),
+            area,
+        );
+
+        let [body_area, hint_area] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)])
+            .areas(area.inner(Margin::new(2, 1)));
+        frame.render_widget(
+            Paragraph::new(Line::from(hint).centered().dark_gray()),
+            hint_area,
+        );
+        body_area
+    }
+
+    /// Renders the main menu
+    fn render_main_menu(&mut self, frame: &mut Frame, view: &RenderView) {
+        let palette = view.settings.theme.get_palette();
+        let body = Self::render_menu_frame(
+            frame,
+            view.area,
+            " MAIN MENU ",
+            "[W/S or ↑/↓: Select] [A/D or ←/→: Change] [Enter: Choose] [Esc: Back]",
+            palette,
+        );
+
+        let entries: Vec<String> = MainMenuItem::ALL
+            .iter()
+            .map(|item| match item {
+                MainMenuItem::Mode => format!(
+                    "{}: {} handling",
+                    item.get_label(),
+                    view.movement_model.get_name()
+                ),
+                MainMenuItem::Players if view.co_op => format!("{}: 2 (co-op)", item.get_label()),
+                MainMenuItem::Players => format!("{}: 1", item.get_label()),
+                _ => item.get_label().to_string(),
+            })
+            .collect();
+        Self::render_menu(
+            frame,
+            body,
+            "GALAGIA",
+            &entries,
+            view.main_menu.index,
+            palette,
+        );
+    }
+
+    /// Renders the settings screen
+    fn render_settings(&mut self, frame: &mut Frame, view: &RenderView) {
+        let palette = view.settings.theme.get_palette();
+        let body = Self::render_menu_frame(
+            frame,
+            view.area,
+            " SETTINGS ",
+            "[W/S or ↑/↓: Select] [A/D or ←/→: Change] [Enter: Next] [Esc: Back]",
+            palette,
+        );
+
+        let entries: Vec<String> = SettingsItem::ALL
+            .iter()
+            .map(|&item| match item {
+                SettingsItem::Controls | SettingsItem::Back => item.get_label().to_string(),
+                _ => format!(
+                    "{}: {}",
+                    item.get_label(),
+                    view.settings.get_value_label(item)
+                ),
+            })
+            .collect();
+        Self::render_menu(
+            frame,
+            body,
+            "SETTINGS",
+            &entries,
+            view.settings_menu.index,
+            palette,
+        );
+    }
+
+    /// Renders the full high-score table opened from the main menu
+    fn render_high_score_screen(&mut self, frame: &mut Frame, view: &RenderView) {
+        let body = Self::render_menu_frame(
+            frame,
+            view.area,
+            " HIGH SCORES ",
+            "[Enter/Esc: Back]",
+            view.settings.theme.get_palette(),
+        );
+        let [table_area] = Layout::vertical([Constraint::Length(MAX_HIGH_SCORES as u16 + 1)])
+            .flex(Flex::Center)
+            .areas(body);
+        Self::render_high_scores(frame, table_area, view.high_scores, None);
+    }
 
-        let pause_area = Rect {
-            x: area.width / 2 - 15,
-            y: area.height / 2 - 3,
-            width: 30,
-            height: 6,
+    /// Renders the ship selection screen shown before a run
+    fn render_ship_select(&mut self, frame: &mut Frame, view: &RenderView) {
+        let area = view.area;
+        let palette = view.settings.theme.get_palette();
+        let title = if view.co_op {
+            format!(" PLAYER {}: SELECT YOUR SHIP ", view.choosing_player + 1)
+        } else {
+            " SELECT YOUR SHIP ".to_string()
         };
+        frame.render_widget(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .border_style(Style::default().fg(palette.frame)),
+            area,
+        );
+
+        let inner = area.inner(Margin::new(2, 1));
+        let [cards_area, hint_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(inner);
+        let card_areas = Layout::horizontal(vec![Constraint::Fill(1); SHIPS.len()])
+            .spacing(1)
+            .split(cards_area);
+
+        for (index, (ship, card_area)) in SHIPS.iter().zip(card_areas.iter()).enumerate() {
+            let selected = index == view.selected_ship;
+            let block = Block::bordered()
+                .border_type(if selected {
+                    BorderType::Thick
+                } else {
+                    BorderType::Plain
+                })
+                .border_style(Style::default().fg(if selected {
+                    palette.highlight
+                } else {
+                    Color::DarkGray
+                }))
+                .title(Line::from(ship.name).centered().bold());
+            let card_inner = block.inner(*card_area);
+            frame.render_widget(block, *card_area);
+
+            let [sprite_area, stats_area] = Layout::vertical([
+                Constraint::Length(ship.height.min(card_inner.height / 2)),
+                Constraint::Fill(1),
+            ])
+            .areas(card_inner);
+
+            // Ship preview, centered in the top of the card
+            let preview_area = sprite_area.intersection(Rect {
+                x: sprite_area.x + sprite_area.width.saturating_sub(ship.width) / 2,
+                width: ship.width,
+                ..sprite_area
+            });
+            match &mut self.images {
+                Some(images) => frame.render_stateful_widget(
+                    StatefulImage::default(),
+                    preview_area,
+                    &mut images.ships[index],
+                ),
+                None => Self::render_ascii_sprite(
+                    frame,
+                    ship.ascii_sprite,
+                    preview_area,
+                    palette.player,
+                ),
+            }
+
+            // Speed shown as stars, four per cell of movement per frame
+            let speed_stars =
+                ship.handling.max_speed.max(1) as usize * 4 / SUBCELLS_PER_CELL as usize;
+            let stats = vec![
+                Line::from(format!("HP: {}", ship.max_health)).centered(),
+                Line::from(format!("Speed: {}", "*".repeat(speed_stars))).centered(),
+                Line::from(format!("Size: {}x{}", ship.width, ship.height)).centered(),
+                Line::from(format!("Weapon: {}", ship.starting_weapon.get_name())).centered(),
+                Line::from(""),
+                Line::from(ship.passive.get_description()).centered().cyan(),
+            ];
+            frame.render_widget(
+                Paragraph::new(stats)
+                    .wrap(Wrap { trim: true })
+                    .style(Style::default().fg(if selected { Color::White } else { Color::Gray })),
+                stats_area,
+            );
+        }
+
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "[A/D or ←/→: Choose] [Enter/Space: Launch] [Esc: Back] [Q: Quit]",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .centered(),
+            hint_area,
+        );
+    }
 
+    /// Renders the high-score table, highlighting one row (e.g. the run just entered)
+    fn render_high_scores(
+        frame: &mut Frame,
+        area: Rect,
+        table: &HighScoreTable,
+        highlight: Option<usize>,
+    ) {
+        let mut lines = vec![Line::from("HIGH SCORES").centered().yellow().bold()];
+        if table.entries.is_empty() {
+            lines.push(Line::from("No scores yet").centered().dark_gray());
+        }
+
+        let rows = area.height.saturating_sub(1) as usize;
+        for (rank, entry) in table.entries.iter().take(rows).enumerate() {
+            let text = format!(
+                "{:>2}. {:<3} {:>8}  {:02}:{:02}  {}",
+                rank + 1,
+                entry.initials,
+                entry.score,
+                entry.time_survived_secs / 60,
+                entry.time_survived_secs % 60,
+                format_date(entry.timestamp)
+            );
+            let style = if highlight == Some(rank) {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            lines.push(Line::from(Span::styled(text, style)).centered());
+        }
+
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    /// Renders the pause screen with overlay
+    fn render_paused(&mut self, frame: &mut Frame, view: &RenderView) {
+        // The game beneath is drawn first by the screen stack
+        let entries: Vec<String> = PauseMenuItem::ALL
+            .iter()
+            .map(|item| item.get_label().to_string())
+            .collect();
+        let palette = view.settings.theme.get_palette();
+        let body = Self::render_popup(
+            frame,
+            view.area,
+            (24, entries.len() as u16 + 6),
+            "",
+            palette.highlight,
+        );
+        let [menu_area, hint_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(body);
+        Self::render_menu(
+            frame,
+            menu_area,
+            "PAUSED",
+            &entries,
+            view.pause_menu.index,
+            palette,
+        );
         frame.render_widget(
-            Paragraph::new(pause_text)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .border_style(Style::default().fg(Color::Yellow)),
+            Paragraph::new(Line::from("[P/Esc: Resume]").centered().dark_gray()),
+            hint_area,
+        );
+    }
+
+    /// Renders the key bindings editor over the pause menu or settings screen
+    fn render_controls(&mut self, frame: &mut Frame, view: &RenderView) {
+        let palette = view.settings.theme.get_palette();
+        let bindings = &view.settings.bindings;
+        let conflicts = bindings.get_conflicts();
+        let items = ControlsItem::get_all();
+        let label_width = items
+            .iter()
+            .map(|item| item.get_label().len())
+            .max()
+            .unwrap_or(0);
+
+        let mut lines: Vec<Line> = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let text = match item {
+                    ControlsItem::Bind(control) if view.rebinding == Some(*control) => format!(
+                        "{:<width$}  Press a key (Esc: Cancel)",
+                        item.get_label(),
+                        width = label_width
+                    ),
+                    ControlsItem::Bind(control) => format!(
+                        "{:<width$}  {}",
+                        item.get_label(),
+                        bindings.get_keys_label(*control),
+                        width = label_width
+                    ),
+                    _ => item.get_label().to_string(),
+                };
+                let style = if index == view.controls_menu.index {
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(palette.highlight)
+                        .add_modifier(Modifier::BOLD)
+                } else if matches!(item, ControlsItem::Bind(control) if conflicts.contains(control))
+                {
+                    Style::default().fg(Color::Red)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                Line::from(Span::styled(text, style))
+            })
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(match view.binding_notice {
+            Some(notice) => Line::from(notice).centered().light_red(),
+            None if !conflicts.is_empty() => Line::from("Keys in red are bound twice")
+                .centered()
+                .light_red(),
+            None => Line::from(""),
+        });
+        lines.push(
+            Line::from("[Enter: Add key] [←: Remove key] [Esc: Back]")
+                .centered()
+                .dark_gray(),
+        );
+
+        let body = Self::render_popup(
+            frame,
+            view.area,
+            (56, lines.len() as u16 + 2),
+            " CONTROLS ",
+            palette.frame,
+        );
+        frame.render_widget(Paragraph::new(lines), body.inner(Margin::new(1, 0)));
+    }
+
+    /// Renders the yes/no prompt for a destructive pause menu choice
+    fn render_confirmation(&mut self, frame: &mut Frame, view: &RenderView) {
+        let question = view
+            .pending_confirmation
+            .map_or("Are you sure?", |item| item.get_confirmation());
+        let option = |label: &'static str, selected: bool| {
+            if selected {
+                Span::styled(
+                    format!("> {} <", label),
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Red)
+                        .add_modifier(Modifier::BOLD),
                 )
-                .alignment(Alignment::Center),
-            pause_area,
+            } else {
+                Span::styled(format!("  {}  ", label), Style::default().fg(Color::White))
+            }
+        };
+        let lines = vec![
+            Line::from(question).centered().white().bold(),
+            Line::from(""),
+            Line::from(vec![
+                option("Yes", view.confirm_yes),
+                Span::raw("   "),
+                option("No", !view.confirm_yes),
+            ])
+            .centered(),
+            Line::from(""),
+            Line::from("[←/→: Choose] [Enter: Confirm] [Esc: Cancel]")
+                .centered()
+                .dark_gray(),
+        ];
+
+        let width = question.len().max(44) as u16 + 4;
+        let body = Self::render_popup(
+            frame,
+            view.area,
+            (width, lines.len() as u16 + 2),
+            " CONFIRM ",
+            Color::Red,
         );
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), body);
+    }
+
+    /// Clears a bordered box of the given (width, height) centred in `area`,
+    /// shrinking it to fit small terminals, and returns the space inside the border
+    fn render_popup(
+        frame: &mut Frame,
+        area: Rect,
+        (width, height): (u16, u16),
+        title: &str,
+        color: Color,
+    ) -> Rect {
+        let [popup_area] = Layout::horizontal([Constraint::Length(width)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [popup_area] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(popup_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title.to_string())
+            .title_alignment(Alignment::Center)
+            .border_style(Style::default().fg(color));
+        let inner = block.inner(popup_area);
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(block, popup_area);
+        inner
     }
 
     /// Renders the game over screen
-    fn render_game_over(&self, frame: &mut Frame, view: &RenderView) {
+    fn render_game_over(&self, frame: &mut Frame, view: &RenderView, screen: GameState) {
         let area = view.area;
         let minutes = view.elapsed_time_secs / 60;
         let seconds = view.elapsed_time_secs % 60;
 
-        let game_over_text = vec![
+        let mut game_over_text = vec![
             Line::from(""),
             Line::from("╔═══════════════════════════╗").centered().red(),
             Line::from("║      GAME OVER!           ║")
@@ -407,20 +1430,124 @@ impl GameRenderer {
                 .centered()
                 .yellow()
                 .bold(),
-            Line::from(format!("Time Survived: {:02}:{:02}", minutes, seconds))
-                .centered()
-                .cyan()
-                .bold(),
-            Line::from(""),
-            Line::from("Press R to restart").centered().white(),
-            Line::from("Press Q to quit").centered().white(),
         ];
+        // Co-op splits the team score by player
+        if let [player_one, player_two] = view.players {
+            game_over_text.push(
+                Line::from(format!(
+                    "P1: {}  P2: {}",
+                    player_one.score, player_two.score
+                ))
+                .centered()
+                .yellow(),
+            );
+        }
+        game_over_text.extend([Line::from(format!(
+            "Time Survived: {:02}:{:02}",
+            minutes, seconds
+        ))
+        .centered()
+        .cyan()
+        .bold()]);
+
+        if screen == GameState::EnterInitials {
+            game_over_text.push(Line::from(""));
+            game_over_text.push(Line::from("NEW HIGH SCORE!").centered().yellow().bold());
+            game_over_text.push(Line::from("Enter your initials").centered().white());
+            let letters: Vec<Span> = view
+                .initials
+                .letters
+                .iter()
+                .enumerate()
+                .map(|(i, letter)| {
+                    let style = if i == view.initials.cursor {
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD)
+                    };
+                    Span::styled(format!(" {} ", letter), style)
+                })
+                .collect();
+            game_over_text.push(Line::from(letters).centered());
+            game_over_text.push(
+                Line::from("[Up/Down: Letter] [Left/Right: Move] [Type: A-Z 0-9] [Enter: Save]")
+                    .centered()
+                    .dark_gray(),
+            );
+        } else {
+            game_over_text.push(Line::from(""));
+            game_over_text.push(Line::from("Press R to restart").centered().white());
+            game_over_text.push(
+                Line::from("Press Enter for the main menu")
+                    .centered()
+                    .white(),
+            );
+            game_over_text.push(Line::from("Press Q to quit").centered().white());
+        }
+
+        frame.render_widget(Block::default().borders(Borders::ALL), area);
+        let inner = area.inner(Margin::new(1, 1));
+        let [text_area, details_area] = Layout::vertical([
+            Constraint::Length(game_over_text.len() as u16),
+            Constraint::Fill(1),
+        ])
+        .areas(inner);
+        let [stats_area, scores_area] =
+            Layout::horizontal([Constraint::Fill(2), Constraint::Fill(1)])
+                .areas(details_area.inner(Margin::new(1, 1)));
 
         frame.render_widget(
-            Paragraph::new(game_over_text)
-                .block(Block::default().borders(Borders::ALL))
-                .alignment(Alignment::Center),
-            area,
+            Paragraph::new(game_over_text).alignment(Alignment::Center),
+            text_area,
         );
+        Self::render_run_stats(frame, stats_area, view.stats);
+        Self::render_high_scores(frame, scores_area, view.high_scores, view.last_rank);
+    }
+
+    /// Renders the end-of-run results: accuracy and kills on the left, damage and pickups on the right
+    fn render_run_stats(frame: &mut Frame, area: Rect, stats: &RunStats) {
+        let heading = |text: String| Line::from(text).yellow().bold();
+        let breakdown = |counts: &std::collections::BTreeMap<String, u32>| -> Vec<Line> {
+            counts
+                .iter()
+                .map(|(name, count)| Line::from(format!("  {}: {}", name, count)).gray())
+                .collect()
+        };
+
+        let mut combat = vec![
+            Line::from("RESULTS").cyan().bold(),
+            heading(format!("Shots Fired: {}", stats.get_shots_fired())),
+        ];
+        combat.extend(breakdown(&stats.shots_by_weapon));
+        combat.push(heading(format!("Number of Hits: {}", stats.hits)));
+        combat.push(heading(format!(
+            "Hit-Miss Ratio: {:.1}%",
+            stats.get_accuracy()
+        )));
+        combat.push(heading(format!("Kills: {}", stats.get_kills())));
+        combat.extend(breakdown(&stats.kills_by_type));
+
+        let mut survival = vec![
+            Line::from(""),
+            heading(format!("Damage Taken: {}", stats.get_damage_taken())),
+        ];
+        survival.extend(breakdown(&stats.damage_by_source));
+        survival.push(heading(format!("Pickups: {}", stats.get_pickups())));
+        survival.extend(breakdown(&stats.pickups_collected));
+        survival.push(heading(format!(
+            "Bombs Detonated: {}",
+            stats.bombs_detonated
+        )));
+        survival.push(heading(format!("Longest Chain: {}", stats.longest_chain)));
+
+        let [left, right] =
+            Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(area);
+        frame.render_widget(Paragraph::new(combat), left);
+        frame.render_widget(Paragraph::new(survival), right);
     }
 }
@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::entities::{EnemyType, PickupKind, WeaponType};
+use crate::storage;
+
+/// What hurt the player
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DamageSource {
+    /// Enemy projectiles
+    EnemyFire,
+    /// Ramming into an enemy
+    Collision,
+}
+
+impl DamageSource {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            DamageSource::EnemyFire => "Enemy fire",
+            DamageSource::Collision => "Collision",
+        }
+    }
+}
+
+/// Counters gathered over a single run, shown on the game over screen
+/// Maps are keyed by display name so the JSON record reads naturally
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunStats {
+    /// Player projectiles launched (and laser bursts fired) per weapon
+    pub shots_by_weapon: BTreeMap<String, u32>,
+    /// Shots that struck at least one enemy; deflected enemy fire isn't a shot, so its
+    /// hits aren't counted
+    pub hits: u32,
+    pub kills_by_type: BTreeMap<String, u32>,
+    pub damage_by_source: BTreeMap<String, u32>,
+    pub pickups_collected: BTreeMap<String, u32>,
+    pub bombs_detonated: u32,
+    pub longest_chain: u32,
+}
+
+impl RunStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_shots(&mut self, weapon: WeaponType, count: u32) {
+        if count > 0 {
+            *self
+                .shots_by_weapon
+                .entry(weapon.get_name().to_string())
+                .or_default() += count;
+        }
+    }
+
+    pub fn record_hit(&mut self) {
+        self.hits += 1;
+    }
+
+    pub fn record_kill(&mut self, enemy_type: EnemyType) {
+        *self
+            .kills_by_type
+            .entry(enemy_type.get_name().to_string())
+            .or_default() += 1;
+    }
+
+    pub fn record_damage(&mut self, source: DamageSource, amount: u8) {
+        if amount > 0 {
+            *self
+                .damage_by_source
+                .entry(source.get_name().to_string())
+                .or_default() += amount as u32;
+        }
+    }
+
+    pub fn record_pickup(&mut self, kind: PickupKind) {
+        *self
+            .pickups_collected
+            .entry(kind.get_label().to_string())
+            .or_default() += 1;
+    }
+
+    pub fn record_bomb(&mut self) {
+        self.bombs_detonated += 1;
+    }
+
+    pub fn get_shots_fired(&self) -> u32 {
+        self.shots_by_weapon.values().sum()
+    }
+
+    pub fn get_kills(&self) -> u32 {
+        self.kills_by_type.values().sum()
+    }
+
+    pub fn get_damage_taken(&self) -> u32 {
+        self.damage_by_source.values().sum()
+    }
+
+    pub fn get_pickups(&self) -> u32 {
+        self.pickups_collected.values().sum()
+    }
+
+    /// Percentage of shots that hit, 0 if nothing was fired
+    pub fn get_accuracy(&self) -> f32 {
+        let shots = self.get_shots_fired();
+        if shots == 0 {
+            return 0.0;
+        }
+        (self.hits as f32 / shots as f32) * 100.0
+    }
+}
+
+/// A finished run as written to disk for later analysis
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// Unix timestamp (seconds) of when the run ended
+    pub timestamp: u64,
+    pub seed: u64,
+    pub version: String,
+    pub ship: String,
+    pub score: u32,
+    pub time_survived_secs: u64,
+    pub stats: RunStats,
+}
+
+impl RunRecord {
+    /// Where run records are kept: `<data dir>/galagia/runs`
+    pub fn default_dir() -> Option<PathBuf> {
+        storage::data_dir().map(|dir| dir.join("runs"))
+    }
+
+    /// Writes the record into `dir` as `run-<timestamp>-<seed>.json`
+    pub fn save_in(&self, dir: &Path) -> io::Result<PathBuf> {
+        let path = dir.join(format!("run-{}-{:016x}.json", self.timestamp, self.seed));
+        storage::write_json_atomic(&path, self)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_accuracy() {
+        let mut stats = RunStats::new();
+        assert_eq!(stats.get_accuracy(), 0.0);
+
+        stats.record_shots(WeaponType::BasicGun, 3);
+        stats.record_shots(WeaponType::Bug, 1);
+        stats.record_hit();
+        assert_eq!(stats.get_shots_fired(), 4);
+        assert_eq!(stats.get_accuracy(), 25.0);
+    }
+
+    #[test]
+    fn test_counters_group_by_name() {
+        let mut stats = RunStats::new();
+        stats.record_kill(EnemyType::Basic);
+        stats.record_kill(EnemyType::Basic);
+        stats.record_kill(EnemyType::Tank);
+        stats.record_damage(DamageSource::EnemyFire, 10);
+        stats.record_damage(DamageSource::Collision, 20);
+        stats.record_damage(DamageSource::EnemyFire, 0);
+        stats.record_pickup(PickupKind::Repair);
+        stats.record_shots(WeaponType::Laser, 0);
+
+        assert_eq!(stats.kills_by_type["Basic"], 2);
+        assert_eq!(stats.get_kills(), 3);
+        assert_eq!(stats.get_damage_taken(), 30);
+        assert_eq!(stats.damage_by_source.len(), 2);
+        assert_eq!(stats.get_pickups(), 1);
+        assert!(stats.shots_by_weapon.is_empty());
+    }
+
+    #[test]
+    fn test_run_record_round_trip() {
+        let dir = std::env::temp_dir().join(format!("galagia-test-runs-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut stats = RunStats::new();
+        stats.record_kill(EnemyType::Fast);
+        let record = RunRecord {
+            timestamp: 1_767_225_600,
+            seed: 42,
+            version: "0.1.0".to_string(),
+            ship: "Falcon".to_string(),
+            score: 1200,
+            time_survived_secs: 95,
+            stats,
+        };
+
+        let path = record.save_in(&dir).unwrap();
+        let loaded: RunRecord = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(loaded, record);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,503 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::bindings::KeyBindings;
+use crate::entities::{MovementModel, Particle};
+use crate::input::KeyRepeatTimeouts;
+use crate::menu::SettingsItem;
+use crate::storage;
+
+/// Step the volume moves by on the settings screen
+pub const VOLUME_STEP: u8 = 10;
+
+/// How ship and enemy sprites are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderMode {
+    /// Sprite images through the terminal's graphics protocol
+    #[default]
+    Image,
+    /// Plain text sprites that work in any terminal
+    Ascii,
+}
+
+/// How aggressive enemies are
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Scale applied to enemy fire and dive chances
+    pub fn get_aggression(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+        }
+    }
+}
+
+/// How many explosion and shockwave particles are spawned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParticleDensity {
+    Off,
+    Low,
+    #[default]
+    High,
+}
+
+impl ParticleDensity {
+    /// Drops particles from a freshly created effect to match the density
+    pub fn thin(&self, particles: Vec<Particle>) -> Vec<Particle> {
+        match self {
+            ParticleDensity::Off => Vec::new(),
+            ParticleDensity::Low => particles.into_iter().step_by(3).collect(),
+            ParticleDensity::High => particles,
+        }
+    }
+}
+
+/// Colour scheme for borders, menus and the ASCII ship
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorTheme {
+    #[default]
+    Classic,
+    Neon,
+    Mono,
+}
+
+/// Colours picked by a theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Borders around menu screens
+    pub frame: Color,
+    /// Walls either side of the play area
+    pub border: Color,
+    /// Menu headings and the highlighted entry
+    pub highlight: Color,
+    /// Player ship in ASCII mode
+    pub player: Color,
+    /// Player two's ship in co-op, so the two can be told apart
+    pub player_two: Color,
+}
+
+impl ColorTheme {
+    pub fn get_palette(&self) -> Palette {
+        match self {
+            ColorTheme::Classic => Palette {
+                frame: Color::Cyan,
+                border: Color::DarkGray,
+                highlight: Color::Yellow,
+                player: Color::LightGreen,
+                player_two: Color::LightBlue,
+            },
+            ColorTheme::Neon => Palette {
+                frame: Color::LightMagenta,
+                border: Color::Magenta,
+                highlight: Color::LightCyan,
+                player: Color::LightYellow,
+                player_two: Color::LightGreen,
+            },
+            ColorTheme::Mono => Palette {
+                frame: Color::Gray,
+                border: Color::DarkGray,
+                highlight: Color::White,
+                player: Color::White,
+                player_two: Color::Gray,
+            },
+        }
+    }
+}
+
+/// Steps an option through its values, wrapping at both ends
+fn cycle<T: Copy + PartialEq>(values: &[T], current: T, forward: bool) -> T {
+    let index = values.iter().position(|&v| v == current).unwrap_or(0);
+    let len = values.len();
+    let next = if forward {
+        (index + 1) % len
+    } else {
+        (index + len - 1) % len
+    };
+    values[next]
+}
+
+/// Player-adjustable options, saved to the config file and kept across restarts
+/// Fields missing from the file keep their defaults
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Sound effect volume in percent
+    pub volume: u8,
+    pub muted: bool,
+    pub render_mode: RenderMode,
+    /// Frames per second in the HUD
+    pub show_fps: bool,
+    pub difficulty: Difficulty,
+    /// Shake the play area when the ship is hit
+    pub screen_shake: bool,
+    pub particles: ParticleDensity,
+    pub theme: ColorTheme,
+    /// Floating score popups and damage numbers
+    pub floating_text: bool,
+    pub movement_model: MovementModel,
+    /// Steer toward the mouse cursor; left button fires, right button drops a smart bomb
+    pub mouse_control: bool,
+    /// Held-key timing for terminals that don't report key releases
+    pub key_repeat: KeyRepeatTimeouts,
+    /// Keys for each game control, editable on the controls screen
+    pub bindings: KeyBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            volume: 30,
+            muted: false,
+            render_mode: RenderMode::default(),
+            show_fps: true,
+            difficulty: Difficulty::default(),
+            screen_shake: true,
+            particles: ParticleDensity::default(),
+            theme: ColorTheme::default(),
+            floating_text: true,
+            movement_model: MovementModel::default(),
+            mouse_control: false,
+            key_repeat: KeyRepeatTimeouts::default(),
+            bindings: KeyBindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Command line flags accepted by `apply_args`
+    pub const USAGE: &'static str = "usage: galagia [--volume 0-100] [--mute] [--ascii | --images] \
+[--fps | --no-fps] [--no-shake] [--difficulty easy|normal|hard] [--particles off|low|high] \
+[--theme classic|neon|mono] [--mouse | --no-mouse]";
+
+    /// Where the config lives: `<config dir>/galagia/config.toml`
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(storage::GAME_DIR).join("config.toml"))
+    }
+
+    /// Loads settings, falling back to defaults if the file is missing or can't be parsed
+    /// A file that can't be parsed is moved aside to `<name>.corrupt` so the next save doesn't lose it
+    pub fn load(path: &Path) -> Self {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+
+        match toml::from_str(&contents) {
+            Ok(settings) => settings,
+            Err(_) => {
+                let _ = fs::rename(path, path.with_extension("toml.corrupt"));
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the settings atomically so a crash mid-save keeps the old file
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = toml::to_string_pretty(self).map_err(io::Error::other)?;
+        storage::write_atomic(path, &contents)
+    }
+
+    /// Applies command line flags on top of the loaded settings
+    pub fn apply_args<I: IntoIterator<Item = String>>(&mut self, args: I) -> Result<(), String> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value =
+                |name: &str| args.next().ok_or_else(|| format!("{} needs a value", name));
+            match arg.as_str() {
+                "--volume" => {
+                    let volume = value("--volume")?;
+                    self.volume = volume
+                        .parse::<u8>()
+                        .map_err(|_| format!("invalid volume '{}'", volume))?
+                        .min(100);
+                }
+                "--mute" => self.muted = true,
+                "--ascii" => self.render_mode = RenderMode::Ascii,
+                "--images" => self.render_mode = RenderMode::Image,
+                "--fps" => self.show_fps = true,
+                "--no-fps" => self.show_fps = false,
+                "--no-shake" => self.screen_shake = false,
+                "--mouse" => self.mouse_control = true,
+                "--no-mouse" => self.mouse_control = false,
+                "--difficulty" => self.difficulty = parse_value(&value("--difficulty")?)?,
+                "--particles" => self.particles = parse_value(&value("--particles")?)?,
+                "--theme" => self.theme = parse_value(&value("--theme")?)?,
+                _ => return Err(format!("unknown option '{}'", arg)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves a settings screen option to its next (or previous) value
+    pub fn adjust(&mut self, item: SettingsItem, forward: bool) {
+        match item {
+            SettingsItem::Volume => {
+                self.volume = if forward {
+                    self.volume.saturating_add(VOLUME_STEP).min(100)
+                } else {
+                    self.volume.saturating_sub(VOLUME_STEP)
+                };
+            }
+            SettingsItem::Mute => self.muted = !self.muted,
+            SettingsItem::RenderMode => {
+                self.render_mode = cycle(
+                    &[RenderMode::Image, RenderMode::Ascii],
+                    self.render_mode,
+                    forward,
+                )
+            }
+            SettingsItem::ShowFps => self.show_fps = !self.show_fps,
+            SettingsItem::Difficulty => {
+                self.difficulty = cycle(
+                    &[Difficulty::Easy, Difficulty::Normal, Difficulty::Hard],
+                    self.difficulty,
+                    forward,
+                )
+            }
+            SettingsItem::ScreenShake => self.screen_shake = !self.screen_shake,
+            SettingsItem::Particles => {
+                self.particles = cycle(
+                    &[
+                        ParticleDensity::Off,
+                        ParticleDensity::Low,
+                        ParticleDensity::High,
+                    ],
+                    self.particles,
+                    forward,
+                )
+            }
+            SettingsItem::Theme => {
+                self.theme = cycle(
+                    &[ColorTheme::Classic, ColorTheme::Neon, ColorTheme::Mono],
+                    self.theme,
+                    forward,
+                )
+            }
+            SettingsItem::FloatingText => self.floating_text = !self.floating_text,
+            SettingsItem::Handling => self.movement_model = self.movement_model.toggled(),
+            SettingsItem::Mouse => self.mouse_control = !self.mouse_control,
+            SettingsItem::Controls | SettingsItem::Back => {}
+        }
+    }
+
+    /// Takes one option's value from another copy of the settings, e.g. to save an in-game
+    /// change without the command-line overrides held alongside it
+    /// `Controls` copies the key bindings
+    pub fn copy_item(&mut self, from: &Settings, item: SettingsItem) {
+        match item {
+            SettingsItem::Volume => self.volume = from.volume,
+            SettingsItem::Mute => self.muted = from.muted,
+            SettingsItem::RenderMode => self.render_mode = from.render_mode,
+            SettingsItem::ShowFps => self.show_fps = from.show_fps,
+            SettingsItem::Difficulty => self.difficulty = from.difficulty,
+            SettingsItem::ScreenShake => self.screen_shake = from.screen_shake,
+            SettingsItem::Particles => self.particles = from.particles,
+            SettingsItem::Theme => self.theme = from.theme,
+            SettingsItem::FloatingText => self.floating_text = from.floating_text,
+            SettingsItem::Handling => self.movement_model = from.movement_model,
+            SettingsItem::Mouse => self.mouse_control = from.mouse_control,
+            SettingsItem::Controls => self.bindings = from.bindings.clone(),
+            SettingsItem::Back => {}
+        }
+    }
+
+    /// Current value of an option as shown on the settings screen
+    pub fn get_value_label(&self, item: SettingsItem) -> String {
+        let on_off = |on: bool| if on { "On" } else { "Off" }.to_string();
+        match item {
+            SettingsItem::Volume => format!("{}%", self.volume),
+            SettingsItem::Mute => on_off(self.muted),
+            SettingsItem::RenderMode => match self.render_mode {
+                RenderMode::Image => "Images".to_string(),
+                RenderMode::Ascii => "ASCII".to_string(),
+            },
+            SettingsItem::ShowFps => on_off(self.show_fps),
+            SettingsItem::Difficulty => format!("{:?}", self.difficulty),
+            SettingsItem::ScreenShake => on_off(self.screen_shake),
+            SettingsItem::Particles => format!("{:?}", self.particles),
+            SettingsItem::Theme => format!("{:?}", self.theme),
+            SettingsItem::FloatingText => on_off(self.floating_text),
+            SettingsItem::Handling => self.movement_model.get_name().to_string(),
+            SettingsItem::Mouse => on_off(self.mouse_control),
+            SettingsItem::Controls | SettingsItem::Back => String::new(),
+        }
+    }
+
+    /// Sound effect volume from 0.0 to 1.0, taking mute into account
+    pub fn get_effective_volume(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.volume as f32 / 100.0
+        }
+    }
+}
+
+/// Parses a lowercase option name (e.g. "hard") into one of the settings enums
+fn parse_value<T: for<'de> Deserialize<'de>>(value: &str) -> Result<T, String> {
+    T::deserialize(serde::de::value::StrDeserializer::<serde::de::value::Error>::new(value))
+        .map_err(|_| format!("invalid value '{}'", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::Control;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_settings_round_trip_through_toml() {
+        let path = std::env::temp_dir()
+            .join(format!("galagia-test-config-{}", std::process::id()))
+            .join("config.toml");
+        let settings = Settings {
+            difficulty: Difficulty::Hard,
+            theme: ColorTheme::Neon,
+            movement_model: MovementModel::Inertial,
+            ..Settings::default()
+        };
+        settings.save(&path).unwrap();
+
+        assert_eq!(Settings::load(&path), settings);
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_partial_or_broken_config_uses_defaults() {
+        let partial: Settings = toml::from_str("volume = 70\ntheme = \"mono\"").unwrap();
+        assert_eq!(partial.volume, 70);
+        assert_eq!(partial.theme, ColorTheme::Mono);
+        assert_eq!(partial.difficulty, Difficulty::Normal);
+
+        let dir = std::env::temp_dir().join(format!("galagia-test-badcfg-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        fs::write(&path, "volume = [").unwrap();
+        assert_eq!(Settings::load(&path), Settings::default());
+
+        // The broken file is kept aside so saving the defaults doesn't overwrite it
+        Settings::default().save(&path).unwrap();
+        let corrupt = fs::read_to_string(dir.join("config.toml.corrupt")).unwrap();
+        assert_eq!(corrupt, "volume = [");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_bad_binding_keeps_the_rest_of_the_config() {
+        let dir = std::env::temp_dir().join(format!("galagia-test-badbind-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        fs::write(
+            &path,
+            "volume = 70\n[bindings]\nfire = [\"j\"]\ndash = [\"Ctrl+Nope\"]\n",
+        )
+        .unwrap();
+
+        let mut settings = Settings::load(&path);
+        assert_eq!(settings.volume, 70);
+        assert_eq!(settings.bindings.get_keys_label(Control::Fire), "J");
+        assert_eq!(settings.bindings.get_keys_label(Control::Dash), "E");
+        assert_eq!(settings.bindings.take_load_warnings().len(), 1);
+        assert!(!dir.join("config.toml.corrupt").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_args_override_settings() {
+        let mut settings = Settings::default();
+        settings
+            .apply_args(args(&[
+                "--volume",
+                "150",
+                "--ascii",
+                "--difficulty",
+                "easy",
+                "--particles",
+                "low",
+                "--no-fps",
+                "--mouse",
+            ]))
+            .unwrap();
+        assert_eq!(settings.volume, 100);
+        assert_eq!(settings.render_mode, RenderMode::Ascii);
+        assert_eq!(settings.difficulty, Difficulty::Easy);
+        assert_eq!(settings.particles, ParticleDensity::Low);
+        assert!(!settings.show_fps);
+        assert!(settings.mouse_control);
+
+        assert!(settings.apply_args(args(&["--theme", "plaid"])).is_err());
+        assert!(settings.apply_args(args(&["--volume"])).is_err());
+        assert!(settings.apply_args(args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn test_copy_item_leaves_other_overrides_unsaved() {
+        let saved = Settings {
+            volume: 80,
+            ..Settings::default()
+        };
+        let mut session = saved.clone();
+        session
+            .apply_args(args(&["--volume", "10", "--ascii"]))
+            .unwrap();
+
+        // Only the option changed in game reaches the saved copy
+        session.adjust(SettingsItem::Difficulty, true);
+        let mut to_save = saved.clone();
+        to_save.copy_item(&session, SettingsItem::Difficulty);
+        assert_eq!(to_save.difficulty, Difficulty::Hard);
+        assert_eq!(to_save.volume, 80);
+        assert_eq!(to_save.render_mode, RenderMode::Image);
+
+        // What the player saw is what gets saved
+        session.adjust(SettingsItem::Volume, true);
+        to_save.copy_item(&session, SettingsItem::Volume);
+        assert_eq!(to_save.volume, 20);
+    }
+
+    #[test]
+    fn test_adjust_cycles_and_clamps() {
+        let mut settings = Settings::default();
+        settings.adjust(SettingsItem::Difficulty, true);
+        assert_eq!(settings.difficulty, Difficulty::Hard);
+        settings.adjust(SettingsItem::Difficulty, true);
+        assert_eq!(settings.difficulty, Difficulty::Easy);
+        settings.adjust(SettingsItem::Difficulty, false);
+        assert_eq!(settings.difficulty, Difficulty::Hard);
+
+        settings.volume = 95;
+        settings.adjust(SettingsItem::Volume, true);
+        assert_eq!(settings.volume, 100);
+        settings.volume = 5;
+        settings.adjust(SettingsItem::Volume, false);
+        assert_eq!(settings.volume, 0);
+
+        settings.adjust(SettingsItem::Mute, true);
+        assert_eq!(settings.get_effective_volume(), 0.0);
+    }
+
+    #[test]
+    fn test_particle_density_thins_effects() {
+        let burst: Vec<Particle> = (0..9).map(|i| Particle::new(i, 0, 0, 0, 10, '*')).collect();
+        assert_eq!(ParticleDensity::High.thin(burst.clone()).len(), 9);
+        assert_eq!(ParticleDensity::Low.thin(burst.clone()).len(), 3);
+        assert!(ParticleDensity::Off.thin(burst).is_empty());
+    }
+}
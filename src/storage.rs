@@ -0,0 +1,36 @@
+use serde::Serialize;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Directory name used under the platform data and config directories
+pub const GAME_DIR: &str = "galagia";
+
+/// The game's folder in the user's data directory, e.g. `~/.local/share/galagia`
+pub fn data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(GAME_DIR))
+}
+
+/// Writes a file atomically: a temp file next to the target is written and
+/// synced, then renamed over it, so a crash never leaves half a file
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+    {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp_path, path)
+}
+
+/// Writes a value as pretty JSON atomically (see [`write_atomic`])
+pub fn write_json_atomic<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(value).map_err(io::Error::other)?;
+    write_atomic(path, &json)
+}
@@ -13,7 +13,7 @@ fn check_collision(x1: u16, y1: u16, w1: u16, h1: u16, x2: u16, y2: u16, w2: u16
 #[test]
 fn test_player_projectile_hits_enemy() {
     let enemy = Enemy::new_in_formation(20, 10, EnemyType::Basic, 0, (0, 0));
-    let projectile = Projectile::new(22, 12, ProjectileOwner::Player);
+    let projectile = Projectile::new(22, 12, ProjectileOwner::Player(0));
 
     // Check collision
     let collision = check_collision(
@@ -53,7 +53,7 @@ fn test_enemy_projectile_hits_player() {
 #[test]
 fn test_no_collision_when_far_apart() {
     let enemy = Enemy::new_in_formation(20, 10, EnemyType::Basic, 0, (0, 0));
-    let projectile = Projectile::new(50, 12, ProjectileOwner::Player);
+    let projectile = Projectile::new(50, 12, ProjectileOwner::Player(0));
 
     // Check collision
     let collision = check_collision(
@@ -87,7 +87,7 @@ fn test_enemy_destroyed_gives_correct_points() {
 #[test]
 fn test_enemy_takes_damage_and_dies() {
     let mut enemy = Enemy::new_in_formation(20, 10, EnemyType::Basic, 0, (0, 0));
-    let projectile = Projectile::new(22, 12, ProjectileOwner::Player);
+    let projectile = Projectile::new(22, 12, ProjectileOwner::Player(0));
 
     // Simulate hit - Basic enemy has 15 health, projectile does 10 damage
     assert!(enemy.is_alive());
@@ -144,7 +144,7 @@ fn test_player_weapon_switch_changes_projectile_count() {
 
 #[test]
 fn test_multiple_projectiles_move_independently() {
-    let mut player_proj = Projectile::new(10, 10, ProjectileOwner::Player);
+    let mut player_proj = Projectile::new(10, 10, ProjectileOwner::Player(0));
     let mut enemy_proj = Projectile::new(20, 10, ProjectileOwner::Enemy);
 
     player_proj.update();